@@ -6,7 +6,10 @@ use crate::Pallet as DapiStaking;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::traits::{Get, OnInitialize, OnUnbalanced};
 use frame_system::{Pallet as System, RawOrigin};
-use sp_runtime::traits::{Bounded, One};
+use sp_runtime::{
+	traits::{Bounded, One},
+	Perbill,
+};
 
 const SEED: u32 = 9000;
 const BLOCK_REWARD: u32 = 1000;
@@ -22,8 +25,13 @@ fn initialize<T: Config>() {
 	GeneralEraInfo::<T>::remove_all(None);
 	ProviderEraStake::<T>::remove_all(None);
 	GeneralStakerInfo::<T>::remove_all(None);
+	ProviderStakers::<T>::remove_all(None);
 	CurrentEra::<T>::kill();
 	BlockRewardAccumulator::<T>::kill();
+	UnappliedSlashes::<T>::remove_all(None);
+	SlashedDeposits::<T>::remove_all(None);
+	JailedProviders::<T>::remove_all(None);
+	JailedEras::<T>::remove_all(None);
 
 	// Initialize the first block
 	DapiStaking::<T>::on_unbalanced(T::Currency::issue(BLOCK_REWARD.into()));
@@ -209,6 +217,33 @@ benchmarks! {
 
 	force_new_era {
 	}: _(RawOrigin::Root)
+
+	cancel_deferred_slash {
+		initialize::<T>();
+		let (_, provider_id) = register_provider::<T>()?;
+
+		let era = DapiStaking::<T>::current_era() + 1;
+		UnappliedSlashes::<T>::append(era, UnappliedSlash { provider_id: provider_id.clone(), fraction: Perbill::from_percent(10) });
+
+	}: _(RawOrigin::Root, provider_id.clone(), era)
+	verify {
+		assert_last_event::<T>(Event::<T>::SlashCancelled{provider_id, era}.into());
+	}
+
+	slash {
+		let s in 0 .. T::MaxNumberOfStakersPerProvider::get() - 1;
+
+		initialize::<T>();
+		let (_, provider_id) = register_provider::<T>()?;
+		prepare_stake::<T>(s, &provider_id, SEED)?;
+		let slash = UnappliedSlash { provider_id: provider_id.clone(), fraction: Perbill::from_percent(10) };
+
+	}: {
+		DapiStaking::<T>::apply_slash(slash);
+	}
+	verify {
+		assert!(!DapiStaking::<T>::slashed_deposit(&provider_id).is_zero());
+	}
 }
 
 #[cfg(test)]
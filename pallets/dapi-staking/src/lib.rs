@@ -6,10 +6,11 @@ use frame_system::{self as system};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Zero},
-	RuntimeDebug,
+	Perbill, RuntimeDebug,
 };
 use sp_std::{ops::Add, prelude::*};
 
+pub mod migrations;
 pub mod pallet;
 pub mod weights;
 
@@ -50,6 +51,15 @@ impl<AccountId> ProviderInfo<AccountId> {
 	}
 }
 
+/// Slash reported for a provider, waiting for its era to be applied.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UnappliedSlash<ProviderId> {
+	/// Slashed provider.
+	pub provider_id: ProviderId,
+	/// Part of the operator deposit and of the stakes to slash.
+	pub fraction: Perbill,
+}
+
 /// Mode of era-forcing.
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
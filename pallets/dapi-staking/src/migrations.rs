@@ -0,0 +1,33 @@
+//! Storage migrations of the dapi-staking pallet.
+
+use super::*;
+use frame_support::{
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Index the stakers of every provider in `ProviderStakers`, so that slashing a provider no
+	/// longer goes through `GeneralStakerInfo` as a whole.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads: Weight = 0;
+		let mut writes: Weight = 0;
+		for (staker, provider_id, staker_info) in GeneralStakerInfo::<T>::iter() {
+			reads += 1;
+			if !staker_info.latest_staked_value().is_zero() {
+				ProviderStakers::<T>::insert(&provider_id, &staker, ());
+				writes += 1;
+			}
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
@@ -35,6 +35,9 @@ pub(crate) const MINIMUM_REMAINING_AMOUNT: Balance = 1;
 pub(crate) const MAX_UNLOCKING_CHUNKS: u32 = 4;
 pub(crate) const UNBONDING_PERIOD: EraIndex = 3;
 pub(crate) const MAX_ERA_STAKE_VALUES: u32 = 8;
+pub(crate) const OUT_OF_SYNC_SLASH: u32 = 10;
+pub(crate) const BAD_PERFORMANCE_SLASH: u32 = 5;
+pub(crate) const SLASH_DEFER_DURATION: EraIndex = 2;
 
 // Do note that this needs to at least be 3 for tests to be valid. It can be greater but not
 // smaller.
@@ -131,6 +134,10 @@ parameter_types! {
 	pub const MaxUnlockingChunks: u32 = MAX_UNLOCKING_CHUNKS;
 	pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
 	pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+	pub const OutOfSyncSlash: Perbill = Perbill::from_percent(OUT_OF_SYNC_SLASH);
+	pub const BadPerformanceSlash: Perbill = Perbill::from_percent(BAD_PERFORMANCE_SLASH);
+	pub const SlashStakers: bool = true;
+	pub const SlashDeferDuration: EraIndex = SLASH_DEFER_DURATION;
 }
 
 impl pallet_dapi_staking::Config for TestRuntime {
@@ -147,6 +154,11 @@ impl pallet_dapi_staking::Config for TestRuntime {
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type UnbondingPeriod = UnbondingPeriod;
 	type MaxEraStakeValues = MaxEraStakeValues;
+	type OutOfSyncSlash = OutOfSyncSlash;
+	type BadPerformanceSlash = BadPerformanceSlash;
+	type SlashStakers = SlashStakers;
+	type SlashDeferDuration = SlashDeferDuration;
+	type OnSlash = ();
 	type WeightInfo = weights::SubstrateWeight<TestRuntime>;
 }
 
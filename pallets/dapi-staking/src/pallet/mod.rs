@@ -3,8 +3,8 @@ use frame_support::{
 	dispatch::{DispatchResult, RawOrigin},
 	ensure,
 	traits::{
-		Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
-		ReservableCurrency, WithdrawReasons,
+		BalanceStatus, Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier,
+		LockableCurrency, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
 	PalletId,
@@ -14,9 +14,9 @@ use sp_runtime::{
 	traits::{AccountIdConversion, CheckedAdd, Saturating, Zero},
 	ArithmeticError, Perbill,
 };
-use sp_std::convert::From;
+use sp_std::{convert::From, vec};
 
-use pallet_dapi::DapiStaking;
+use pallet_dapi::{DapiStaking, ProviderDeactivateReason};
 
 const STAKING_ID: LockIdentifier = *b"dapistak";
 
@@ -35,8 +35,12 @@ pub mod pallet {
 		<T as frame_system::Config>::AccountId,
 	>>::NegativeImbalance;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -101,6 +105,26 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxEraStakeValues: Get<u32>;
 
+		/// Part of the operator deposit and stakes slashed for a provider out of sync.
+		#[pallet::constant]
+		type OutOfSyncSlash: Get<Perbill>;
+
+		/// Part of the operator deposit and stakes slashed for a provider performing badly.
+		#[pallet::constant]
+		type BadPerformanceSlash: Get<Perbill>;
+
+		/// Whether the stakes of nominators are slashed along with the operator's own.
+		#[pallet::constant]
+		type SlashStakers: Get<bool>;
+
+		/// Number of eras a slash is deferred for, during which root can cancel it.
+		/// Should not exceed `UnbondingPeriod` so that the slashed funds can't be withdrawn first.
+		#[pallet::constant]
+		type SlashDeferDuration: Get<EraIndex>;
+
+		/// Handler for the slashed funds.
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
 		/// The overarching event type.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
@@ -170,6 +194,34 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Accounts that currently stake on a provider, so that they can be slashed without going
+	/// through the stakes of every provider.
+	#[pallet::storage]
+	pub(crate) type ProviderStakers<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::ProviderId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Providers jailed by dAPI, they earn no rewards while jailed.
+	#[pallet::storage]
+	pub(crate) type JailedProviders<T: Config> = StorageMap<_, Blake2_128Concat, T::ProviderId, ()>;
+
+	/// Eras in which a provider was jailed at some point, nothing is earned for them.
+	#[pallet::storage]
+	pub type JailedEras<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::ProviderId, Twox64Concat, EraIndex, ()>;
+
+	/// Part of the register deposit of a provider that was slashed, what is left stays reserved
+	/// from its operator.
+	#[pallet::storage]
+	#[pallet::getter(fn slashed_deposit)]
+	pub type SlashedDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProviderId, BalanceOf<T>, ValueQuery>;
+
+	/// Slashes to apply at the start of an era.
+	#[pallet::storage]
+	#[pallet::getter(fn unapplied_slashes)]
+	pub type UnappliedSlashes<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, Vec<UnappliedSlash<T::ProviderId>>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -196,6 +248,16 @@ pub mod pallet {
 		},
 		/// Provider removed from dapi staking.
 		ProviderUnregistered(T::ProviderId),
+		/// Unregistered provider is registered again.
+		ProviderReactivated(T::ProviderId),
+		/// Provider is handed over to a new operator along with its deposit and self-stake.
+		OperatorChanged { provider_id: T::ProviderId, from: T::AccountId, to: T::AccountId },
+		/// Provider is reported for an offence, it is slashed in `apply_era` unless cancelled.
+		SlashDeferred { provider_id: T::ProviderId, fraction: Perbill, apply_era: EraIndex },
+		/// Funds of an operator or staker are slashed.
+		Slashed { who: T::AccountId, provider_id: T::ProviderId, amount: BalanceOf<T> },
+		/// Deferred slashes of a provider are cancelled.
+		SlashCancelled { provider_id: T::ProviderId, era: EraIndex },
 	}
 
 	#[pallet::error]
@@ -236,6 +298,12 @@ pub mod pallet {
 		NotUnregisteredProvider,
 		/// The provider is already registered by other account
 		AlreadyRegisteredProvider,
+		/// The new operator already stakes on the provider.
+		NewOperatorStaked,
+		/// No slash of the provider is deferred to the era.
+		NoDeferredSlash,
+		/// A slash of the provider is deferred, it must be applied or cancelled first.
+		SlashPending,
 	}
 
 	#[pallet::hooks]
@@ -254,7 +322,8 @@ pub mod pallet {
 
 				let reward = BlockRewardAccumulator::<T>::take();
 				Self::reward_balance_snapshot(previous_era, reward);
-				let consumed_weight = Self::rotate_staking_info(previous_era);
+				let consumed_weight = Self::rotate_staking_info(previous_era)
+					.saturating_add(Self::apply_deferred_slashes(next_era));
 
 				if force_new_era {
 					ForceEra::<T>::put(Forcing::NotForcing);
@@ -267,6 +336,10 @@ pub mod pallet {
 				T::DbWeight::get().reads(4)
 			}
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
@@ -311,6 +384,15 @@ pub mod pallet {
 
 			Self::update_staker_info(&staker, &provider_id, Default::default());
 
+			// Keep track of the stakes left so that they are carried over if the provider is
+			// reactivated.
+			ProviderEraStake::<T>::mutate(&provider_id, unregistered_era, |value| {
+				if let Some(x) = value {
+					x.total = x.total.saturating_sub(staked_value);
+					x.number_of_stakers = x.number_of_stakers.saturating_sub(1);
+				}
+			});
+
 			let current_era = Self::current_era();
 			GeneralEraInfo::<T>::mutate(&current_era, |value| {
 				if let Some(x) = value {
@@ -356,7 +438,8 @@ pub mod pallet {
 			provider_info.unreserved = true;
 			RegisteredProviders::<T>::insert(&provider_id, provider_info);
 
-			let unreserve_amount = T::RegisterDeposit::get();
+			let unreserve_amount =
+				T::RegisterDeposit::get().saturating_sub(SlashedDeposits::<T>::take(&provider_id));
 			T::Currency::unreserve(&operator, unreserve_amount);
 
 			Self::deposit_event(Event::<T>::WithdrawFromUnregistered {
@@ -576,8 +659,11 @@ pub mod pallet {
 
 			let (_, stakers_joint_reward) =
 				Self::operator_stakers_split(&staking_info, &reward_and_stake);
-			let staker_reward =
-				Perbill::from_rational(staked, staking_info.total) * stakers_joint_reward;
+			let staker_reward = if JailedEras::<T>::contains_key(&provider_id, era) {
+				Zero::zero()
+			} else {
+				Perbill::from_rational(staked, staking_info.total) * stakers_joint_reward
+			};
 
 			let reward_imbalance = T::Currency::withdraw(
 				&Self::account_id(),
@@ -628,8 +714,11 @@ pub mod pallet {
 			let reward_and_stake =
 				Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
 
-			let (operator_reward, _) =
-				Self::operator_stakers_split(&provider_stake_info, &reward_and_stake);
+			let (operator_reward, _) = if JailedEras::<T>::contains_key(&provider_id, era) {
+				(Zero::zero(), Zero::zero())
+			} else {
+				Self::operator_stakers_split(&provider_stake_info, &reward_and_stake)
+			};
 
 			let reward_imbalance = T::Currency::withdraw(
 				&Self::account_id(),
@@ -669,6 +758,28 @@ pub mod pallet {
 			ForceEra::<T>::put(Forcing::ForceNew);
 			Ok(())
 		}
+
+		/// Cancel the slashes of a provider deferred to `era`.
+		///
+		/// The dispatch origin must be Root.
+		#[pallet::weight(T::WeightInfo::cancel_deferred_slash())]
+		pub fn cancel_deferred_slash(
+			origin: OriginFor<T>,
+			provider_id: T::ProviderId,
+			#[pallet::compact] era: EraIndex,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			UnappliedSlashes::<T>::try_mutate(era, |slashes| -> DispatchResult {
+				let count = slashes.len();
+				slashes.retain(|slash| slash.provider_id != provider_id);
+				ensure!(slashes.len() < count, Error::<T>::NoDeferredSlash);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::SlashCancelled { provider_id, era });
+			Ok(())
+		}
 	}
 
 	impl<T: Config>
@@ -720,6 +831,192 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		fn jail(provider_id: T::ProviderId) -> DispatchResultWithPostInfo {
+			ensure!(Self::is_active_provider(&provider_id), Error::<T>::NotOperatedProvider);
+
+			JailedProviders::<T>::insert(&provider_id, ());
+			JailedEras::<T>::insert(&provider_id, Self::current_era(), ());
+
+			Ok(().into())
+		}
+
+		fn unjail(provider_id: T::ProviderId) -> DispatchResultWithPostInfo {
+			ensure!(
+				JailedProviders::<T>::take(&provider_id).is_some(),
+				Error::<T>::NotOperatedProvider
+			);
+
+			Ok(().into())
+		}
+
+		fn slash(
+			provider_id: T::ProviderId,
+			reason: ProviderDeactivateReason,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				RegisteredProviders::<T>::contains_key(&provider_id),
+				Error::<T>::NotOperatedProvider
+			);
+
+			let fraction = match reason {
+				ProviderDeactivateReason::BadPerformance { .. } => T::BadPerformanceSlash::get(),
+				ProviderDeactivateReason::OutOfSync => T::OutOfSyncSlash::get(),
+				ProviderDeactivateReason::UnRegistered => Perbill::zero(),
+			};
+			if fraction.is_zero() {
+				return Ok(().into())
+			}
+
+			let slash = UnappliedSlash { provider_id: provider_id.clone(), fraction };
+			let defer_duration = T::SlashDeferDuration::get();
+			if defer_duration.is_zero() {
+				Self::apply_slash(slash);
+			} else {
+				let apply_era = Self::current_era() + defer_duration;
+				UnappliedSlashes::<T>::append(apply_era, slash);
+				Self::deposit_event(Event::<T>::SlashDeferred { provider_id, fraction, apply_era });
+			}
+
+			Ok(().into())
+		}
+
+		fn reactivate(
+			operator: T::AccountId,
+			provider_id: T::ProviderId,
+			deposit: <<T as Config>::Currency as Currency<
+				<T as frame_system::Config>::AccountId,
+			>>::Balance,
+		) -> DispatchResultWithPostInfo {
+			let mut provider_info = RegisteredProviders::<T>::get(&provider_id)
+				.ok_or(Error::<T>::NotOperatedProvider)?;
+			ensure!(provider_info.operator == operator, Error::<T>::NotOwnedProvider);
+			let unregistered_era = if let ProviderState::Unregistered(e) = provider_info.state {
+				e
+			} else {
+				return Err(Error::<T>::NotUnregisteredProvider.into())
+			};
+
+			// The stakes that were not withdrawn are carried over, withdrawals after the unbonding
+			// period are already deducted from them.
+			let current_era = Self::current_era();
+			if current_era > unregistered_era {
+				if let Some(mut staking_info) =
+					Self::provider_stake_info(&provider_id, unregistered_era)
+				{
+					staking_info.provider_reward_claimed = false;
+					ProviderEraStake::<T>::insert(&provider_id, current_era, staking_info);
+				}
+			}
+
+			// The register deposit is topped up to its full amount again.
+			let missing_deposit = if provider_info.unreserved {
+				T::RegisterDeposit::get()
+			} else {
+				Self::slashed_deposit(&provider_id)
+			};
+			if !missing_deposit.is_zero() {
+				T::Currency::reserve(&operator, missing_deposit)?;
+			}
+			provider_info.unreserved = false;
+			SlashedDeposits::<T>::remove(&provider_id);
+
+			provider_info.state = ProviderState::Registered;
+			RegisteredProviders::<T>::insert(&provider_id, provider_info);
+
+			if !deposit.is_zero() {
+				Self::stake(RawOrigin::Signed(operator).into(), provider_id.clone(), deposit)?;
+			}
+
+			Self::deposit_event(Event::<T>::ProviderReactivated(provider_id));
+			Ok(().into())
+		}
+
+		fn change_operator(
+			provider_id: T::ProviderId,
+			new_operator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let mut provider_info = RegisteredProviders::<T>::get(&provider_id)
+				.ok_or(Error::<T>::NotOperatedProvider)?;
+			let operator = provider_info.operator.clone();
+			ensure!(
+				Self::staker_info(&new_operator, &provider_id).is_empty(),
+				Error::<T>::NewOperatorStaked
+			);
+			// A deferred slash would otherwise fall on the new operator.
+			let current_era = Self::current_era();
+			ensure!(
+				(current_era..=current_era.saturating_add(T::SlashDeferDuration::get())).all(
+					|era| Self::unapplied_slashes(era)
+						.iter()
+						.all(|slash| slash.provider_id != provider_id)
+				),
+				Error::<T>::SlashPending
+			);
+
+			if !provider_info.unreserved {
+				// Only what is left of the deposit after slashes is moved.
+				let deposit =
+					T::RegisterDeposit::get().saturating_sub(Self::slashed_deposit(&provider_id));
+				T::Currency::repatriate_reserved(
+					&operator,
+					&new_operator,
+					deposit,
+					BalanceStatus::Reserved,
+				)?;
+			}
+
+			// The self-stake is moved with its unclaimed eras, unbonding chunks stay with the
+			// previous operator.
+			let staker_info = Self::staker_info(&operator, &provider_id);
+			let staked_value = staker_info.latest_staked_value();
+			if !staked_value.is_zero() {
+				let mut ledger = Self::ledger(&operator);
+				ledger.locked = ledger.locked.saturating_sub(staked_value);
+				Self::update_ledger(&operator, ledger);
+
+				T::Currency::transfer(
+					&operator,
+					&new_operator,
+					staked_value,
+					ExistenceRequirement::AllowDeath,
+				)?;
+
+				let mut ledger = Self::ledger(&new_operator);
+				ledger.locked =
+					ledger.locked.checked_add(&staked_value).ok_or(ArithmeticError::Overflow)?;
+				Self::update_ledger(&new_operator, ledger);
+			}
+			Self::update_staker_info(&operator, &provider_id, Default::default());
+			Self::update_staker_info(&new_operator, &provider_id, staker_info);
+
+			provider_info.operator = new_operator.clone();
+			RegisteredProviders::<T>::insert(&provider_id, provider_info);
+
+			Self::deposit_event(Event::<T>::OperatorChanged {
+				provider_id,
+				from: operator,
+				to: new_operator,
+			});
+			Ok(().into())
+		}
+
+		fn current_era() -> EraIndex {
+			Self::current_era()
+		}
+
+		fn minimum_register_deposit() -> BalanceOf<T> {
+			T::RegisterDeposit::get().saturating_add(T::MinimumStakingAmount::get())
+		}
+
+		fn slash_weight() -> Weight {
+			if !T::SlashDeferDuration::get().is_zero() {
+				return T::DbWeight::get().reads_writes(1, 1)
+			}
+			let stakers =
+				if T::SlashStakers::get() { T::MaxNumberOfStakersPerProvider::get() } else { 0 };
+			T::WeightInfo::slash(stakers)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -742,11 +1039,19 @@ pub mod pallet {
 
 		/// Update the staker info for the `(staker, provider_id)` pairing.
 		/// If staker_info is empty, remove it from the DB. Otherwise, store it.
+		///
+		/// `ProviderStakers` is kept in sync with the stakers whose latest stake is not zero.
 		fn update_staker_info(
 			staker: &T::AccountId,
 			provider_id: &T::ProviderId,
 			staker_info: StakerInfo<BalanceOf<T>>,
 		) {
+			if staker_info.latest_staked_value().is_zero() {
+				ProviderStakers::<T>::remove(provider_id, staker);
+			} else {
+				ProviderStakers::<T>::insert(provider_id, staker, ());
+			}
+
 			if staker_info.is_empty() {
 				GeneralStakerInfo::<T>::remove(staker, provider_id)
 			} else {
@@ -794,6 +1099,13 @@ pub mod pallet {
 					continue
 				}
 
+				// Jailed providers keep missing out on rewards in the next era
+				consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+				if JailedProviders::<T>::contains_key(&provider_id) {
+					JailedEras::<T>::insert(&provider_id, next_era, ());
+					consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().writes(1));
+				}
+
 				// Copy data from era `X` to era `X + 1`
 				if let Some(mut staking_info) = Self::provider_stake_info(&provider_id, current_era)
 				{
@@ -810,6 +1122,130 @@ pub mod pallet {
 			consumed_weight
 		}
 
+		/// Applies the slashes deferred to the given era.
+		fn apply_deferred_slashes(era: EraIndex) -> u64 {
+			let mut consumed_weight = T::DbWeight::get().reads_writes(1, 1);
+
+			for slash in UnappliedSlashes::<T>::take(era) {
+				consumed_weight = consumed_weight.saturating_add(Self::apply_slash(slash));
+			}
+
+			consumed_weight
+		}
+
+		/// Slashes the reserved deposit and self-stake of the provider operator, as well as the
+		/// stakes of its nominators if `SlashStakers` is set.
+		///
+		/// Slashing nominators scales with the number of stakers of the provider, which is bounded
+		/// by `MaxNumberOfStakersPerProvider`.
+		pub(crate) fn apply_slash(slash: UnappliedSlash<T::ProviderId>) -> u64 {
+			let UnappliedSlash { provider_id, fraction } = slash;
+			let mut consumed_weight = T::DbWeight::get().reads(1);
+
+			let provider_info = match RegisteredProviders::<T>::get(&provider_id) {
+				Some(provider_info) => provider_info,
+				None => return consumed_weight,
+			};
+			let operator = provider_info.operator;
+
+			if !provider_info.unreserved {
+				// Never slash more than what is left of the deposit, other reserves of the
+				// operator are not tied to the provider.
+				let register_deposit = T::RegisterDeposit::get();
+				let slashed = Self::slashed_deposit(&provider_id);
+				let slash_value =
+					(fraction * register_deposit).min(register_deposit.saturating_sub(slashed));
+				let (imbalance, _) = T::Currency::slash_reserved(&operator, slash_value);
+				SlashedDeposits::<T>::insert(
+					&provider_id,
+					slashed.saturating_add(imbalance.peek()),
+				);
+				Self::deposit_event(Event::<T>::Slashed {
+					who: operator.clone(),
+					provider_id: provider_id.clone(),
+					amount: imbalance.peek(),
+				});
+				T::OnSlash::on_unbalanced(imbalance);
+
+				consumed_weight =
+					consumed_weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+			}
+
+			// Stakes of an unregistered provider stay recorded in the era it was unregistered in.
+			let stake_era = match provider_info.state {
+				ProviderState::Unregistered(era) => era,
+				ProviderState::Registered => Self::current_era(),
+			};
+
+			let mut stakers = vec![operator.clone()];
+			if T::SlashStakers::get() {
+				for staker in ProviderStakers::<T>::iter_key_prefix(&provider_id) {
+					consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+					if staker != operator {
+						stakers.push(staker);
+					}
+				}
+			}
+
+			for staker in stakers {
+				let weight = Self::slash_staker(&staker, &provider_id, stake_era, fraction);
+				consumed_weight = consumed_weight.saturating_add(weight);
+			}
+
+			consumed_weight
+		}
+
+		/// Slashes part of the stake of a staker on a provider, starting from the current era.
+		fn slash_staker(
+			staker: &T::AccountId,
+			provider_id: &T::ProviderId,
+			stake_era: EraIndex,
+			fraction: Perbill,
+		) -> u64 {
+			let mut staker_info = Self::staker_info(staker, provider_id);
+			let slash_value = fraction * staker_info.latest_staked_value();
+			if slash_value.is_zero() {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (imbalance, missing) = T::Currency::slash(staker, slash_value);
+			let slashed_value = slash_value.saturating_sub(missing);
+
+			let current_era = Self::current_era();
+			// Can't fail since stakes are never recorded for eras after the current one.
+			let _ = staker_info.unstake(current_era, slashed_value);
+			let fully_slashed = staker_info.latest_staked_value().is_zero();
+			Self::update_staker_info(staker, provider_id, staker_info);
+
+			let mut ledger = Self::ledger(staker);
+			ledger.locked = ledger.locked.saturating_sub(slashed_value);
+			Self::update_ledger(staker, ledger);
+
+			ProviderEraStake::<T>::mutate(provider_id, stake_era, |value| {
+				if let Some(x) = value {
+					x.total = x.total.saturating_sub(slashed_value);
+					if fully_slashed {
+						x.number_of_stakers = x.number_of_stakers.saturating_sub(1);
+					}
+				}
+			});
+			GeneralEraInfo::<T>::mutate(&current_era, |value| {
+				if let Some(x) = value {
+					x.staked = x.staked.saturating_sub(slashed_value);
+					x.locked = x.locked.saturating_sub(slashed_value);
+				}
+			});
+
+			Self::deposit_event(Event::<T>::Slashed {
+				who: staker.clone(),
+				provider_id: provider_id.clone(),
+				amount: slashed_value,
+			});
+			T::OnSlash::on_unbalanced(imbalance);
+
+			T::DbWeight::get().reads_writes(7, 7)
+		}
+
 		/// Returns available staking balance for the potential staker
 		fn available_staking_balance(
 			staker: &T::AccountId,
@@ -3,6 +3,10 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-04-05, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! PLACEHOLDER WEIGHTS: most weights below were written or adjusted by hand after that run,
+//! estimated from the storage accesses of each call rather than measured. Regenerate this file
+//! with the command below before relying on them.
 
 // Executed Command:
 // ./target/release/massbit-collator
@@ -53,6 +57,10 @@ pub trait WeightInfo {
 	fn claim_operator() -> Weight;
 	#[rustfmt::skip]
 	fn force_new_era() -> Weight;
+	#[rustfmt::skip]
+	fn cancel_deferred_slash() -> Weight;
+	#[rustfmt::skip]
+	fn slash(s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_dapi_staking using the Substrate node and recommended hardware.
@@ -64,12 +72,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: DapiStaking Ledger (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
 	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
 	#[rustfmt::skip]
 	fn withdraw_from_unregistered_staker() -> Weight {
 		(72_275_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
 	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
@@ -121,20 +130,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
 	// Storage: DapiStaking ProviderEraStake (r:1 w:0)
 	// Storage: DapiStaking GeneralEraInfo (r:1 w:0)
+	// Storage: DapiStaking JailedEras (r:1 w:0)
 	#[rustfmt::skip]
 	fn claim_staker() -> Weight {
 		(47_505_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: DapiStaking RegisteredProviders (r:1 w:0)
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
 	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
 	// Storage: DapiStaking GeneralEraInfo (r:1 w:0)
+	// Storage: DapiStaking JailedEras (r:1 w:0)
 	#[rustfmt::skip]
 	fn claim_operator() -> Weight {
 		(40_409_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: DapiStaking ForceEra (r:0 w:1)
@@ -143,6 +154,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(2_125_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: DapiStaking UnappliedSlashes (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_deferred_slash() -> Weight {
+		(9_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: DapiStaking RegisteredProviders (r:1 w:0)
+	// Storage: DapiStaking SlashedDeposits (r:1 w:1)
+	// Storage: DapiStaking ProviderStakers (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DapiStaking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
+	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
+	#[rustfmt::skip]
+	fn slash(s: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((31_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(s as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -153,12 +190,13 @@ impl WeightInfo for () {
 	// Storage: DapiStaking Ledger (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
 	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
 	#[rustfmt::skip]
 	fn withdraw_from_unregistered_staker() -> Weight {
 		(72_275_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
 	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
@@ -210,20 +248,22 @@ impl WeightInfo for () {
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
 	// Storage: DapiStaking ProviderEraStake (r:1 w:0)
 	// Storage: DapiStaking GeneralEraInfo (r:1 w:0)
+	// Storage: DapiStaking JailedEras (r:1 w:0)
 	#[rustfmt::skip]
 	fn claim_staker() -> Weight {
 		(47_505_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: DapiStaking RegisteredProviders (r:1 w:0)
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
 	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
 	// Storage: DapiStaking GeneralEraInfo (r:1 w:0)
+	// Storage: DapiStaking JailedEras (r:1 w:0)
 	#[rustfmt::skip]
 	fn claim_operator() -> Weight {
 		(40_409_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: DapiStaking ForceEra (r:0 w:1)
@@ -232,4 +272,30 @@ impl WeightInfo for () {
 		(2_125_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: DapiStaking UnappliedSlashes (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_deferred_slash() -> Weight {
+		(9_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: DapiStaking RegisteredProviders (r:1 w:0)
+	// Storage: DapiStaking SlashedDeposits (r:1 w:1)
+	// Storage: DapiStaking ProviderStakers (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DapiStaking Ledger (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
+	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
+	#[rustfmt::skip]
+	fn slash(s: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((31_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((7 as Weight).saturating_mul(s as Weight)))
+	}
 }
@@ -0,0 +1,46 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_dapi::{ProjectMetadata, ProviderCapabilities, ProviderMetadata, RateLimit};
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query dAPI projects and providers.
+	pub trait DapiApi<AccountId, MassbitId, Hash> where
+		AccountId: Codec,
+		MassbitId: Codec,
+		Hash: Codec,
+	{
+		/// Get the metadata of a project.
+		fn project_metadata(
+			project_id: MassbitId,
+		) -> Option<ProjectMetadata<Vec<u8>, Vec<Vec<u8>>>>;
+
+		/// Check that an API key is valid for an active project.
+		fn is_valid_api_key(project_id: MassbitId, key_hash: Hash) -> bool;
+
+		/// Get the rate limit gateways enforce for a project.
+		fn project_rate_limit(project_id: MassbitId) -> Option<RateLimit>;
+
+		/// Get a page of the projects owned by a consumer, skipping the first `start` ones.
+		fn projects_by_consumer(consumer: AccountId, start: u32, limit: u32) -> Vec<MassbitId>;
+
+		/// Get a page of the providers run by an operator, skipping the first `start` ones.
+		fn providers_by_operator(operator: AccountId, start: u32, limit: u32) -> Vec<MassbitId>;
+
+		/// Get a page of the active providers serving a chain, skipping the first `start` ones.
+		fn active_providers_by_chain(chain_id: Vec<u8>, start: u32, limit: u32) -> Vec<MassbitId>;
+
+		/// Get the metadata of a provider.
+		fn provider_metadata(provider_id: MassbitId) -> Option<ProviderMetadata<Vec<u8>>>;
+
+		/// Get a page of the active providers serving a chain that support every given
+		/// capability, skipping the first `start` matching ones.
+		fn active_providers_with_capabilities(
+			chain_id: Vec<u8>,
+			capabilities: ProviderCapabilities,
+			start: u32,
+			limit: u32,
+		) -> Vec<MassbitId>;
+	}
+}
@@ -3,18 +3,52 @@
 use super::*;
 use crate::Pallet as Dapi;
 
+use codec::Decode;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, Hash};
+use sp_std::vec;
 
 const SEED: u32 = 9000;
 
 fn initialize<T: Config>() {
 	// Remove everything from storage
 	Projects::<T>::remove_all(None);
+	LastUsagePeriod::<T>::remove_all(None);
+	PendingUsageReports::<T>::remove_all(None);
+	QuotaThresholds::<T>::remove_all(None);
+	ProjectUsageByEra::<T>::remove_all(None);
+	ProjectUsageEras::<T>::remove_all(None);
+	AutoTopUps::<T>::remove_all(None);
+	PendingProjectTransfers::<T>::remove_all(None);
+	ProjectMembers::<T>::remove_all(None);
+	ProjectApiKeys::<T>::remove_all(None);
+	ProjectRateLimits::<T>::remove_all(None);
+	ProjectRateLimitCaps::<T>::remove_all(None);
+	ProjectMetadataStore::<T>::remove_all(None);
+	ProjectMetadataDeposits::<T>::remove_all(None);
+	ProviderMetadataStore::<T>::remove_all(None);
+	PendingProviderTransfers::<T>::remove_all(None);
+	PendingProviderBonds::<T>::remove_all(None);
+	ProviderMetadataDeposits::<T>::remove_all(None);
+	PendingUsages::<T>::remove_all(None);
+	UsageChallengeEnds::<T>::remove_all(None);
+	ProviderOffenceCounts::<T>::remove_all(None);
+	OffenceDeactivations::<T>::remove_all(None);
+	JailReleases::<T>::remove_all(None);
+	UsageQuorumConfig::<T>::kill();
 	Providers::<T>::remove_all(None);
+	ProjectsByConsumer::<T>::remove_all(None);
+	ProvidersByOperator::<T>::remove_all(None);
+	ActiveProvidersByChain::<T>::remove_all(None);
 	Regulators::<T>::kill();
 	ChainIds::<T>::kill();
+	ChainQuotaPrices::<T>::remove_all(None);
+	QuotaPrice::<T>::kill();
+	T::Currency::make_free_balance_be(
+		&Dapi::<T>::escrow_account_id(),
+		T::Currency::minimum_balance(),
+	);
 
 	Dapi::<T>::add_chain_id(RawOrigin::Root.into(), "eth.mainnet".into()).unwrap();
 }
@@ -24,6 +58,186 @@ fn assert_last_event<T: Config>(event: <T as Config>::Event) {
 	frame_system::Pallet::<T>::assert_last_event(event.into());
 }
 
+/// Build a distinct Massbit id from the given index.
+fn massbit_id<T: Config>(index: u32) -> T::MassbitId {
+	let mut id = [0u8; 36];
+	id[..4].copy_from_slice(&index.to_le_bytes());
+	T::MassbitId::decode(&mut &id[..]).unwrap()
+}
+
+/// Usage period covering only the current block.
+fn current_period<T: Config>() -> UsagePeriod<T::BlockNumber> {
+	let now = frame_system::Pallet::<T>::block_number();
+	UsagePeriod { start: now, end: now }
+}
+
+/// Used to add a new regulator.
+fn prepare_regulator<T: Config>() -> Result<T::AccountId, &'static str> {
+	let regulator: T::AccountId = account("regulator", 10000, SEED);
+	T::Currency::make_free_balance_be(&regulator, BalanceOf::<T>::max_value() / 2u32.into());
+	Dapi::<T>::add_regulator(RawOrigin::Root.into(), regulator.clone())?;
+	Ok(regulator)
+}
+
+/// Used to register a project on `eth.mainnet` with a large enough quota.
+fn prepare_project<T: Config>(index: u32) -> Result<(T::AccountId, T::MassbitId), &'static str> {
+	let consumer: T::AccountId = account("consumer", 10000, SEED);
+	T::Currency::make_free_balance_be(&consumer, BalanceOf::<T>::max_value());
+
+	let project_id = massbit_id::<T>(index);
+	let amount = BalanceOf::<T>::max_value() / 1_000_000u32.into();
+
+	Dapi::<T>::register_project(
+		RawOrigin::Signed(consumer.clone()).into(),
+		project_id.clone(),
+		"eth.mainnet".into(),
+		amount,
+	)?;
+	Ok((consumer, project_id))
+}
+
+/// Used to self-register a provider on `eth.mainnet`.
+fn prepare_provider<T: Config>() -> Result<(T::AccountId, T::MassbitId), &'static str> {
+	let operator: T::AccountId = account("operator", 10000, SEED);
+	T::Currency::make_free_balance_be(&operator, BalanceOf::<T>::max_value());
+
+	let provider_id = T::MassbitId::default();
+	Dapi::<T>::self_register_provider(
+		RawOrigin::Signed(operator.clone()).into(),
+		provider_id.clone(),
+		ProviderType::Node,
+		"eth.mainnet".into(),
+		BalanceOf::<T>::max_value() / 2u32.into(),
+	)?;
+	Ok((operator, provider_id))
+}
+
+/// Used to self-register a provider on `eth.mainnet` that waits for approval, as if
+/// `RequireProviderApproval` was set.
+fn prepare_pending_provider<T: Config>() -> Result<T::MassbitId, &'static str> {
+	let operator: T::AccountId = account("operator", 10000, SEED);
+	T::Currency::make_free_balance_be(&operator, BalanceOf::<T>::max_value());
+
+	let provider_id = T::MassbitId::default();
+	let bond = BalanceOf::<T>::max_value() / 2u32.into();
+	T::Currency::reserve(&operator, bond)?;
+	PendingProviderBonds::<T>::insert(&provider_id, bond);
+	ProvidersByOperator::<T>::insert(&operator, &provider_id, ());
+	Providers::<T>::insert(
+		&provider_id,
+		Provider {
+			provider_type: ProviderType::Node,
+			operator,
+			chain_id: b"eth.mainnet".to_vec().try_into().unwrap(),
+			state: ProviderState::PendingApproval,
+		},
+	);
+	Ok(provider_id)
+}
+
+/// Used to make applying usage to a project as costly as possible: the usage crosses every quota
+/// threshold, triggers an auto top-up and prunes a full usage history.
+///
+/// Returns the usage to report, which uses up the quota of the project.
+fn prepare_costly_usage<T: Config>(
+	consumer: &T::AccountId,
+	project_id: &T::MassbitId,
+) -> Result<u128, &'static str> {
+	let thresholds = (1..=T::MaxQuotaThresholds::get())
+		.map(|i| Percent::from_rational(i, T::MaxQuotaThresholds::get()))
+		.collect::<Vec<_>>();
+	Dapi::<T>::set_quota_thresholds(
+		RawOrigin::Signed(consumer.clone()).into(),
+		project_id.clone(),
+		thresholds,
+	)?;
+	Dapi::<T>::set_auto_top_up(
+		RawOrigin::Signed(consumer.clone()).into(),
+		project_id.clone(),
+		BalanceOf::<T>::max_value() / 1_000_000u32.into(),
+		BalanceOf::<T>::max_value() / 1_000u32.into(),
+	)?;
+
+	fill_usage_history::<T>(project_id, T::UsageHistoryDepth::get());
+
+	let project = Dapi::<T>::projects(project_id).ok_or("project not registered")?;
+	Ok(project.chains[0].quota)
+}
+
+/// Used to record usage of a project in the `count` eras before the current one, as far as the
+/// current era allows it. A history of `UsageHistoryDepth` eras has a stale oldest era.
+fn fill_usage_history<T: Config>(project_id: &T::MassbitId, count: u32) {
+	let era = T::DapiStaking::current_era();
+	let mut eras = Vec::new();
+	for recorded in era.saturating_sub(count)..era {
+		ProjectUsageByEra::<T>::insert(project_id, recorded, 1u128);
+		eras.push(recorded);
+	}
+	ProjectUsageEras::<T>::insert(project_id, frame_support::BoundedVec::try_from(eras).unwrap());
+}
+
+/// Used to report usage of a new project and have its consumer dispute it.
+fn prepare_usage_dispute<T: Config>(
+) -> Result<(T::MassbitId, UsagePeriod<T::BlockNumber>), &'static str> {
+	let regulator = prepare_regulator::<T>()?;
+	let (consumer, project_id) = prepare_project::<T>(0)?;
+	let period = current_period::<T>();
+
+	Dapi::<T>::submit_project_usage(
+		RawOrigin::Signed(regulator).into(),
+		project_id.clone(),
+		"eth.mainnet".into(),
+		1u128,
+		period,
+	)?;
+	Dapi::<T>::dispute_project_usage(
+		RawOrigin::Signed(consumer).into(),
+		project_id.clone(),
+		"eth.mainnet".into(),
+		period,
+	)?;
+	Ok((project_id, period))
+}
+
+/// Used to fill the member list of a project up to one below its limit.
+fn prepare_members<T: Config>(
+	consumer: &T::AccountId,
+	project_id: &T::MassbitId,
+) -> Result<(), &'static str> {
+	for i in 1..T::MaxProjectMembers::get() {
+		let member: T::AccountId = account("member", i, SEED);
+		Dapi::<T>::add_project_member(
+			RawOrigin::Signed(consumer.clone()).into(),
+			project_id.clone(),
+			member,
+			ProjectRole::Viewer,
+		)?;
+	}
+	Ok(())
+}
+
+/// Used to fill the API keys of a project up to one below its limit.
+fn prepare_api_keys<T: Config>(
+	consumer: &T::AccountId,
+	project_id: &T::MassbitId,
+) -> Result<(), &'static str> {
+	for i in 1..T::MaxApiKeys::get() {
+		Dapi::<T>::add_api_key(
+			RawOrigin::Signed(consumer.clone()).into(),
+			project_id.clone(),
+			T::Hashing::hash_of(&i),
+			None,
+		)?;
+	}
+	Ok(())
+}
+
+/// Used to build the largest project metadata.
+fn max_metadata<T: Config>() -> (Vec<u8>, Vec<u8>, Vec<Vec<u8>>) {
+	let text = vec![b'x'; T::MaxMetadataLength::get() as usize];
+	(text.clone(), text.clone(), vec![text; T::MaxAllowedOrigins::get() as usize])
+}
+
 benchmarks! {
 	register_project {
 		initialize::<T>();
@@ -38,6 +252,19 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone(), chain_id, amount.clone())
 
+	create_project {
+		initialize::<T>();
+
+		let consumer: T::AccountId = account("consumer", 10000, SEED);
+		T::Currency::make_free_balance_be(&consumer, BalanceOf::<T>::max_value());
+
+		let amount = BalanceOf::<T>::max_value() / 2u32.into();
+
+	}: _(RawOrigin::Signed(consumer), "eth.mainnet".into(), amount)
+	verify {
+		assert_eq!(Projects::<T>::iter().count(), 1);
+	}
+
 	deposit_project {
 		initialize::<T>();
 
@@ -52,6 +279,352 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone(), amount.clone())
 
+	add_project_chain {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let chain_id: Vec<u8> = "bsc.mainnet".into();
+		Dapi::<T>::add_chain_id(RawOrigin::Root.into(), chain_id.clone())?;
+		let amount = BalanceOf::<T>::max_value() / 1_000_000u32.into();
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), chain_id.clone(), amount)
+	verify {
+		let project = Dapi::<T>::projects(&project_id).unwrap();
+		assert_eq!(project.chains.len(), 2);
+	}
+
+	submit_project_usage {
+		initialize::<T>();
+
+		let regulator = prepare_regulator::<T>()?;
+		let (_, project_id) = prepare_project::<T>(0)?;
+
+		let period = current_period::<T>();
+
+		let chain_id: Vec<u8> = "eth.mainnet".into();
+
+	}: _(RawOrigin::Signed(regulator), project_id.clone(), chain_id.clone(), 1u128, period)
+	verify {
+		assert_last_event::<T>(Event::<T>::UsageReportFinalized { project_id, chain_id, period, usage: 1 }.into());
+	}
+
+	submit_project_usage_batch {
+		let n in 1 .. T::MaxUsageBatchSize::get();
+
+		initialize::<T>();
+
+		// Every report reaches the quorum along with a matching one that is already pending.
+		let regulator = prepare_regulator::<T>()?;
+		let other_regulator: T::AccountId = account("regulator", 10001, SEED);
+		T::Currency::make_free_balance_be(&other_regulator, BalanceOf::<T>::max_value() / 2u32.into());
+		Dapi::<T>::add_regulator(RawOrigin::Root.into(), other_regulator.clone())?;
+		Dapi::<T>::set_usage_quorum(RawOrigin::Root.into(), 2, Perbill::zero())?;
+
+		let period = current_period::<T>();
+		let mut usages = Vec::new();
+		for i in 0..n {
+			let (consumer, project_id) = prepare_project::<T>(i)?;
+			let usage = prepare_costly_usage::<T>(&consumer, &project_id)?;
+			Dapi::<T>::submit_project_usage(
+				RawOrigin::Signed(other_regulator.clone()).into(),
+				project_id.clone(),
+				"eth.mainnet".into(),
+				usage,
+				period,
+			)?;
+			usages.push((project_id, "eth.mainnet".into(), usage, period));
+		}
+
+	}: _(RawOrigin::Signed(regulator), usages)
+
+	apply_project_usage {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let usage = prepare_costly_usage::<T>(&consumer, &project_id)?;
+		let chain_id: frame_support::BoundedVec<u8, T::ChainIdMaxLength> =
+			b"eth.mainnet".to_vec().try_into().unwrap();
+
+	}: {
+		Dapi::<T>::apply_project_usage(&project_id, &chain_id, usage)
+			.map_err(|_| "failed to apply usage")?;
+	}
+	verify {
+		assert_eq!(AutoTopUps::<T>::get(&project_id).map(|top_up| top_up.spent.is_zero()), Some(false));
+	}
+
+	add_project_member {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		prepare_members::<T>(&consumer, &project_id)?;
+		let member: T::AccountId = account("member", 0, SEED);
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), member.clone(), ProjectRole::Admin)
+	verify {
+		assert!(Dapi::<T>::has_project_role(&project_id, &member, ProjectRole::Admin));
+	}
+
+	remove_project_member {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		prepare_members::<T>(&consumer, &project_id)?;
+		let member: T::AccountId = account("member", 1, SEED);
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), member.clone())
+	verify {
+		assert!(!Dapi::<T>::has_project_role(&project_id, &member, ProjectRole::Viewer));
+	}
+
+	set_project_rate_limit {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let limit = RateLimit { requests_per_second: Some(100), requests_per_day: Some(1_000_000) };
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), limit)
+	verify {
+		assert_eq!(Dapi::<T>::project_rate_limit(&project_id), Some(limit));
+	}
+
+	set_project_rate_limit_cap {
+		initialize::<T>();
+
+		let regulator = prepare_regulator::<T>()?;
+		let (_, project_id) = prepare_project::<T>(0)?;
+		let limit = RateLimit { requests_per_second: Some(100), requests_per_day: Some(1_000_000) };
+
+	}: _(RawOrigin::Signed(regulator), project_id.clone(), limit)
+	verify {
+		assert_eq!(Dapi::<T>::project_rate_limit(&project_id), Some(limit));
+	}
+
+	add_api_key {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		prepare_api_keys::<T>(&consumer, &project_id)?;
+		let key_hash = T::Hashing::hash_of(&0u32);
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), key_hash, None)
+	verify {
+		assert!(Dapi::<T>::is_valid_api_key(&project_id, &key_hash));
+	}
+
+	revoke_api_key {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		prepare_api_keys::<T>(&consumer, &project_id)?;
+		let key_hash = T::Hashing::hash_of(&1u32);
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), key_hash)
+	verify {
+		assert!(!Dapi::<T>::is_valid_api_key(&project_id, &key_hash));
+	}
+
+	rotate_api_key {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		prepare_api_keys::<T>(&consumer, &project_id)?;
+		let old_key_hash = T::Hashing::hash_of(&1u32);
+		let new_key_hash = T::Hashing::hash_of(&0u32);
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), old_key_hash, new_key_hash, None)
+	verify {
+		assert!(Dapi::<T>::is_valid_api_key(&project_id, &new_key_hash));
+	}
+
+	set_project_metadata {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let (name, description, allowed_origins) = max_metadata::<T>();
+		Dapi::<T>::set_project_metadata(
+			RawOrigin::Signed(consumer.clone()).into(),
+			project_id.clone(),
+			name.clone(),
+			description.clone(),
+			allowed_origins.clone(),
+		)?;
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), name, description, allowed_origins)
+	verify {
+		assert!(Dapi::<T>::project_metadata(&project_id).is_some());
+	}
+
+	clear_project_metadata {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let (name, description, allowed_origins) = max_metadata::<T>();
+		Dapi::<T>::set_project_metadata(
+			RawOrigin::Signed(consumer.clone()).into(),
+			project_id.clone(),
+			name,
+			description,
+			allowed_origins,
+		)?;
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::ProjectMetadataCleared { project_id }.into());
+	}
+
+	set_quota_thresholds {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let thresholds = (1..=T::MaxQuotaThresholds::get())
+			.map(|i| Percent::from_rational(i, T::MaxQuotaThresholds::get()))
+			.collect::<Vec<_>>();
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), thresholds.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::QuotaThresholdsChanged { project_id, thresholds }.into());
+	}
+
+	set_auto_top_up {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let amount = BalanceOf::<T>::max_value() / 1_000_000u32.into();
+		let max_spend = BalanceOf::<T>::max_value() / 1_000u32.into();
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone(), amount, max_spend)
+	verify {
+		assert_last_event::<T>(Event::<T>::AutoTopUpChanged { project_id, amount, max_spend }.into());
+	}
+
+	dispute_project_usage {
+		initialize::<T>();
+
+		let regulator = prepare_regulator::<T>()?;
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let period = current_period::<T>();
+		let chain_id: Vec<u8> = "eth.mainnet".into();
+		Dapi::<T>::submit_project_usage(RawOrigin::Signed(regulator).into(), project_id.clone(), chain_id.clone(), 1u128, period)?;
+
+	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone(), chain_id.clone(), period)
+	verify {
+		assert_last_event::<T>(Event::<T>::UsageDisputed { project_id, chain_id, period, consumer }.into());
+	}
+
+	resolve_usage_dispute {
+		initialize::<T>();
+
+		let (project_id, period) = prepare_usage_dispute::<T>()?;
+
+	}: _(RawOrigin::Root, project_id.clone(), "eth.mainnet".into(), period, false)
+	verify {
+		let project = Dapi::<T>::projects(&project_id).unwrap();
+		assert_eq!(project.chains[0].usage, 1);
+	}
+
+	set_usage_quorum {
+		initialize::<T>();
+
+		prepare_regulator::<T>()?;
+		let tolerance = Perbill::from_percent(1);
+
+	}: _(RawOrigin::Root, 1, tolerance)
+	verify {
+		assert_last_event::<T>(Event::<T>::UsageQuorumChanged { threshold: 1, tolerance }.into());
+	}
+
+	close_project {
+		initialize::<T>();
+
+		let consumer: T::AccountId = account("consumer", 10000, SEED);
+		T::Currency::make_free_balance_be(&consumer, BalanceOf::<T>::max_value());
+		T::Currency::make_free_balance_be(
+			&T::ProjectRefundAccount::get(),
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+
+		let project_id = T::MassbitId::default();
+		let amount = BalanceOf::<T>::max_value() / 3u32.into();
+
+		let chain_id = "eth.mainnet".into();
+		Dapi::<T>::register_project(RawOrigin::Signed(consumer.clone()).into(), project_id.clone(), chain_id, amount.clone())?;
+
+	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone())
+	verify {
+		let project = Dapi::<T>::projects(&project_id).unwrap();
+		assert_eq!(project.state, ProjectState::Closed);
+	}
+
+	transfer_project {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let new_consumer: T::AccountId = account("new_consumer", 10000, SEED);
+
+	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone(), new_consumer.clone())
+	verify {
+		assert_eq!(Dapi::<T>::pending_project_transfer(&project_id), Some(new_consumer));
+	}
+
+	cancel_project_transfer {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let new_consumer: T::AccountId = account("new_consumer", 10000, SEED);
+		Dapi::<T>::transfer_project(RawOrigin::Signed(consumer.clone()).into(), project_id.clone(), new_consumer)?;
+
+	}: _(RawOrigin::Signed(consumer), project_id.clone())
+	verify {
+		assert!(Dapi::<T>::pending_project_transfer(&project_id).is_none());
+	}
+
+	accept_project_transfer {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		let new_consumer: T::AccountId = account("new_consumer", 10000, SEED);
+		Dapi::<T>::transfer_project(RawOrigin::Signed(consumer.clone()).into(), project_id.clone(), new_consumer.clone())?;
+
+	}: _(RawOrigin::Signed(new_consumer.clone()), project_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::ProjectTransferred { project_id, from: consumer, to: new_consumer }.into());
+	}
+
+	suspend_project {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+
+	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::ProjectSuspended { project_id, by: consumer }.into());
+	}
+
+	resume_project {
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		Dapi::<T>::suspend_project(RawOrigin::Signed(consumer.clone()).into(), project_id.clone())?;
+
+	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::ProjectResumed { project_id, by: consumer }.into());
+	}
+
+	delete_project {
+		let h in 0 .. T::UsageHistoryDepth::get();
+
+		initialize::<T>();
+
+		let (consumer, project_id) = prepare_project::<T>(0)?;
+		fill_usage_history::<T>(&project_id, h);
+
+	}: _(RawOrigin::Signed(consumer.clone()), project_id.clone())
+	verify {
+		assert!(Dapi::<T>::projects(&project_id).is_none());
+	}
+
 	register_provider {
 		initialize::<T>();
 
@@ -79,6 +652,153 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(operator.clone()), provider_id.clone())
 
+	create_provider {
+		initialize::<T>();
+
+		let regulator = prepare_regulator::<T>()?;
+		let operator: T::AccountId = account("operator", 10000, SEED);
+
+	}: _(RawOrigin::Signed(regulator), ProviderType::Node, operator, "eth.mainnet".into())
+	verify {
+		assert_eq!(Providers::<T>::iter().count(), 1);
+	}
+
+	self_register_provider {
+		initialize::<T>();
+
+		let operator: T::AccountId = account("operator", 10000, SEED);
+		T::Currency::make_free_balance_be(&operator, BalanceOf::<T>::max_value());
+
+		let provider_id = T::MassbitId::default();
+		let amount = BalanceOf::<T>::max_value() / 2u32.into();
+
+	}: _(RawOrigin::Signed(operator), provider_id.clone(), ProviderType::Gateway, "eth.mainnet".into(), amount)
+	verify {
+		assert!(Providers::<T>::contains_key(&provider_id));
+	}
+
+	approve_provider {
+		initialize::<T>();
+
+		let regulator = prepare_regulator::<T>()?;
+		let provider_id = prepare_pending_provider::<T>()?;
+
+	}: _(RawOrigin::Signed(regulator), provider_id.clone())
+	verify {
+		assert_eq!(Dapi::<T>::providers(&provider_id).unwrap().state, ProviderState::Active);
+	}
+
+	transfer_provider {
+		initialize::<T>();
+
+		let (operator, provider_id) = prepare_provider::<T>()?;
+		let new_operator: T::AccountId = account("new_operator", 10000, SEED);
+
+	}: _(RawOrigin::Signed(operator), provider_id.clone(), new_operator.clone())
+	verify {
+		assert_eq!(Dapi::<T>::pending_provider_transfer(&provider_id), Some(new_operator));
+	}
+
+	cancel_provider_transfer {
+		initialize::<T>();
+
+		let (operator, provider_id) = prepare_provider::<T>()?;
+		let new_operator: T::AccountId = account("new_operator", 10000, SEED);
+		Dapi::<T>::transfer_provider(RawOrigin::Signed(operator.clone()).into(), provider_id.clone(), new_operator)?;
+
+	}: _(RawOrigin::Signed(operator), provider_id.clone())
+	verify {
+		assert!(Dapi::<T>::pending_provider_transfer(&provider_id).is_none());
+	}
+
+	accept_provider_transfer {
+		initialize::<T>();
+
+		let (operator, provider_id) = prepare_provider::<T>()?;
+		let new_operator: T::AccountId = account("new_operator", 10000, SEED);
+		Dapi::<T>::transfer_provider(RawOrigin::Signed(operator.clone()).into(), provider_id.clone(), new_operator.clone())?;
+
+	}: _(RawOrigin::Signed(new_operator.clone()), provider_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::ProviderTransferred { provider_id, from: operator, to: new_operator }.into());
+	}
+
+	report_provider_offence {
+		initialize::<T>();
+
+		let regulator = prepare_regulator::<T>()?;
+		let (_, provider_id) = prepare_provider::<T>()?;
+		let since = frame_system::Pallet::<T>::block_number();
+		let count = T::MaxOffences::get().saturating_sub(1);
+		ProviderOffenceCounts::<T>::insert(&provider_id, ProviderOffences { count, since });
+
+	}: _(RawOrigin::Signed(regulator), provider_id.clone(), ProviderDeactivateReason::OutOfSync)
+	verify {
+		assert_eq!(Dapi::<T>::providers(&provider_id).unwrap().state, ProviderState::InActive);
+	}
+
+	reactivate_provider {
+		initialize::<T>();
+
+		let (operator, provider_id) = prepare_provider::<T>()?;
+		Dapi::<T>::unregister_provider(RawOrigin::Signed(operator.clone()).into(), provider_id.clone())?;
+		let amount = BalanceOf::<T>::max_value() / 4u32.into();
+
+	}: _(RawOrigin::Signed(operator), provider_id.clone(), amount)
+	verify {
+		assert_eq!(Dapi::<T>::providers(&provider_id).unwrap().state, ProviderState::Active);
+	}
+
+	set_provider_metadata {
+		initialize::<T>();
+
+		let (operator, provider_id) = prepare_provider::<T>()?;
+		let text = vec![b'x'; T::MaxMetadataLength::get() as usize];
+		let capabilities = ProviderCapabilities { archive: true, trace: true };
+		Dapi::<T>::set_provider_metadata(
+			RawOrigin::Signed(operator.clone()).into(),
+			provider_id.clone(),
+			text.clone(),
+			text.clone(),
+			text.clone(),
+			capabilities,
+		)?;
+
+	}: _(RawOrigin::Signed(operator), provider_id.clone(), text.clone(), text.clone(), text, capabilities)
+	verify {
+		assert!(Dapi::<T>::provider_metadata(&provider_id).is_some());
+	}
+
+	clear_provider_metadata {
+		initialize::<T>();
+
+		let (operator, provider_id) = prepare_provider::<T>()?;
+		let text = vec![b'x'; T::MaxMetadataLength::get() as usize];
+		Dapi::<T>::set_provider_metadata(
+			RawOrigin::Signed(operator.clone()).into(),
+			provider_id.clone(),
+			text.clone(),
+			text.clone(),
+			text,
+			Default::default(),
+		)?;
+
+	}: _(RawOrigin::Signed(operator), provider_id.clone())
+	verify {
+		assert!(Dapi::<T>::provider_metadata(&provider_id).is_none());
+	}
+
+	reject_provider {
+		initialize::<T>();
+
+		let regulator = prepare_regulator::<T>()?;
+		let provider_id = prepare_pending_provider::<T>()?;
+
+	}: _(RawOrigin::Signed(regulator), provider_id.clone())
+	verify {
+		assert_eq!(Dapi::<T>::providers(&provider_id).unwrap().state, ProviderState::Rejected);
+	}
+
 	add_chain_id {
 		ChainIds::<T>::kill();
 
@@ -100,10 +820,35 @@ benchmarks! {
 	remove_regulator {
 		initialize::<T>();
 
+		// Removing the regulator lowers the usage quorum threshold.
 		let regulator: T::AccountId = account("regulator", 10000, SEED);
 		Dapi::<T>::add_regulator(RawOrigin::Root.into(), regulator.clone())?;
+		Dapi::<T>::add_regulator(RawOrigin::Root.into(), account("regulator", 10001, SEED))?;
+		Dapi::<T>::set_usage_quorum(RawOrigin::Root.into(), 2, Perbill::zero())?;
 
 	}: _(RawOrigin::Root, regulator)
+	verify {
+		assert_eq!(Dapi::<T>::usage_quorum().threshold, 1);
+	}
+
+	set_quota_price {
+		let price = BalanceOf::<T>::max_value();
+
+	}: _(RawOrigin::Root, price)
+	verify {
+		assert_last_event::<T>(Event::<T>::QuotaPriceChanged { price }.into());
+	}
+
+	set_chain_quota_price {
+		initialize::<T>();
+
+		let chain_id: Vec<u8> = "eth.mainnet".into();
+		let price = Some(BalanceOf::<T>::max_value());
+
+	}: _(RawOrigin::Root, chain_id.clone(), price)
+	verify {
+		assert_last_event::<T>(Event::<T>::ChainQuotaPriceChanged { chain_id, price }.into());
+	}
 }
 
 #[cfg(test)]
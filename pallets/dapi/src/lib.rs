@@ -1,14 +1,24 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 pub mod types;
 pub mod weights;
 
 use frame_support::{
 	pallet_prelude::DispatchResultWithPostInfo,
-	traits::{Currency, ExistenceRequirement, OnUnbalanced, WithdrawReasons},
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Currency, ExistenceRequirement, OnUnbalanced, ReservableCurrency, WithdrawReasons},
+	transactional,
+	weights::Weight,
+	PalletId,
 };
-use sp_runtime::traits::Scale;
-use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+use sp_io::hashing::blake2_256;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, One, Saturating, Zero},
+	PerThing, Perbill, Percent, SaturatedConversion,
+};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*, vec};
 
 #[cfg(any(feature = "runtime-benchmarks"))]
 pub mod benchmarking;
@@ -21,6 +31,9 @@ pub use weights::WeightInfo;
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -33,8 +46,19 @@ pub mod pallet {
 	/// Blockchain identifier, e.g `eth.mainnet`
 	type ChainId<T> = BoundedVec<u8, <T as Config>::ChainIdMaxLength>;
 
+	type MetadataText<T> = BoundedVec<u8, <T as Config>::MaxMetadataLength>;
+	type ProjectMetadataOf<T> = ProjectMetadata<
+		MetadataText<T>,
+		BoundedVec<MetadataText<T>, <T as Config>::MaxAllowedOrigins>,
+	>;
+	type ProviderMetadataOf<T> = ProviderMetadata<MetadataText<T>>;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -44,7 +68,7 @@ pub mod pallet {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The currency mechanism
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		/// dAPI staking.
 		type DapiStaking: DapiStaking<Self::AccountId, Self::MassbitId, BalanceOf<Self>>;
@@ -52,6 +76,13 @@ pub mod pallet {
 		/// The origin which can add/remove regulators.
 		type UpdateRegulatorOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The origin which can update the quota price.
+		type UpdateQuotaPriceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Price of a single request used until `QuotaPrice` is explicitly set.
+		#[pallet::constant]
+		type DefaultQuotaPrice: Get<BalanceOf<Self>>;
+
 		/// For constraining the maximum length of a chain id.
 		type ChainIdMaxLength: Get<u32>;
 
@@ -59,9 +90,102 @@ pub mod pallet {
 		type MassbitId: Parameter + Member + Default;
 
 		/// Handle project payment as imbalance.
-		type OnProjectPayment: OnUnbalanced<
-			<Self::Currency as Currency<Self::AccountId>>::NegativeImbalance,
-		>;
+		type OnProjectPayment: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The account from which unused quota of closed projects is refunded.
+		type ProjectRefundAccount: Get<Self::AccountId>;
+
+		/// dAPI pallet Id, used to derive the escrow account of project deposits.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Whether project deposits are held in escrow and paid through `OnProjectPayment` only
+		/// as usage is reported.
+		#[pallet::constant]
+		type ProjectDepositEscrow: Get<bool>;
+
+		/// Whether self-registered providers must be approved by a regulator before they become
+		/// active.
+		#[pallet::constant]
+		type RequireProviderApproval: Get<bool>;
+
+		/// Number of blocks a provider is jailed for after a repeated offence, must not be zero.
+		#[pallet::constant]
+		type JailDuration: Get<Self::BlockNumber>;
+
+		/// Number of blocks after the first offence of a provider during which further offences
+		/// add up.
+		#[pallet::constant]
+		type OffenceWindow: Get<Self::BlockNumber>;
+
+		/// Number of offences within the offence window after which a provider is deactivated.
+		/// The first offence is a warning and those in between jail the provider.
+		#[pallet::constant]
+		type MaxOffences: Get<u32>;
+
+		/// Maximum number of usage reports in a single batch.
+		#[pallet::constant]
+		type MaxUsageBatchSize: Get<u32>;
+
+		/// Number of blocks accepted usage stays pending and can be disputed by the consumer.
+		#[pallet::constant]
+		type UsageChallengePeriod: Get<Self::BlockNumber>;
+
+		/// Bond reserved from a regulator for each usage report until the usage is settled.
+		#[pallet::constant]
+		type UsageReportBond: Get<BalanceOf<Self>>;
+
+		/// Bond reserved from a consumer to dispute pending usage.
+		#[pallet::constant]
+		type UsageDisputeBond: Get<BalanceOf<Self>>;
+
+		/// Number of blocks regulators have to resolve a usage dispute, must not be zero.
+		#[pallet::constant]
+		type UsageDisputePeriod: Get<Self::BlockNumber>;
+
+		/// Maximum number of pending usages settled at the start of a block, the rest is carried
+		/// over to the next block. Must not be zero.
+		#[pallet::constant]
+		type MaxUsageAppliedPerBlock: Get<u32>;
+
+		/// Handle bonds forfeited by the losing side of a usage dispute.
+		type OnDisputeSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Maximum number of members of a project, besides its consumer.
+		#[pallet::constant]
+		type MaxProjectMembers: Get<u32>;
+
+		/// Maximum number of chains of a project.
+		#[pallet::constant]
+		type MaxProjectChains: Get<u32>;
+
+		/// Maximum number of API keys of a project.
+		#[pallet::constant]
+		type MaxApiKeys: Get<u32>;
+
+		/// Maximum length of a project metadata field.
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32>;
+
+		/// Maximum number of allowed origins in project metadata.
+		#[pallet::constant]
+		type MaxAllowedOrigins: Get<u32>;
+
+		/// Base deposit reserved for project metadata.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved per byte of project metadata.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// Maximum number of quota thresholds of a project.
+		#[pallet::constant]
+		type MaxQuotaThresholds: Get<u32>;
+
+		/// Number of dAPI staking eras for which project usage history is kept.
+		#[pallet::constant]
+		type UsageHistoryDepth: Get<u32>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -83,6 +207,54 @@ pub mod pallet {
 		PermissionDenied,
 		/// Provider invalid state.
 		InvalidProviderState,
+		/// Quota price must be greater than zero.
+		InvalidQuotaPrice,
+		/// Project invalid state.
+		InvalidProjectState,
+		/// Too many usage reports in a single batch.
+		TooManyUsageReports,
+		/// Usage period is empty or ends in the future.
+		InvalidUsagePeriod,
+		/// Usage period overlaps with an already accepted report.
+		OverlappingUsagePeriod,
+		/// The regulator already reported usage of the project for this period.
+		DuplicateUsageReport,
+		/// Quorum threshold must be between one and the number of regulators.
+		InvalidUsageQuorum,
+		/// Usage of the project is still pending or under dispute.
+		UsageUnderChallenge,
+		/// The challenge period of the usage is over.
+		ChallengePeriodOver,
+		/// The usage is already disputed.
+		AlreadyDisputed,
+		/// The usage is not disputed.
+		NotDisputed,
+		/// The regulator already voted on the dispute.
+		DuplicateDisputeVote,
+		/// Too many quota thresholds.
+		TooManyQuotaThresholds,
+		/// Quota threshold must be greater than zero.
+		InvalidQuotaThreshold,
+		/// Too many members in the project.
+		TooManyProjectMembers,
+		/// Project metadata field is too long.
+		MetadataTooLong,
+		/// Too many allowed origins in project metadata.
+		TooManyAllowedOrigins,
+		/// Too many API keys in the project.
+		TooManyApiKeys,
+		/// API key expiry must be in the future.
+		InvalidApiKeyExpiry,
+		/// Too many chains in the project.
+		TooManyProjectChains,
+		/// Generated id does not decode exactly as a `MassbitId`.
+		BadMassbitId,
+		/// Reactivating a provider without the approval of a regulator requires a deposit.
+		NoReactivationDeposit,
+		/// Deposit must be greater than zero.
+		ZeroDeposit,
+		/// Bond is below the minimum dAPI staking accepts to register a provider.
+		InsufficientBond,
 	}
 
 	#[pallet::event]
@@ -95,10 +267,130 @@ pub mod pallet {
 			chain_id: Vec<u8>,
 			quota: u128,
 		},
+		/// A chain is added to a project.
+		ProjectChainAdded { project_id: T::MassbitId, chain_id: Vec<u8>, quota: u128 },
 		/// Project is deposited.
-		ProjectDeposited { project_id: T::MassbitId, quota: u128 },
-		/// Project reached max quota.
-		ProjectReachedQuota { project_id: T::MassbitId },
+		ProjectDeposited { project_id: T::MassbitId, chain_id: Vec<u8>, quota: u128 },
+		/// Project reached max quota on a chain.
+		ProjectReachedQuota { project_id: T::MassbitId, chain_id: Vec<u8> },
+		/// Project usage on a chain reached one of its quota thresholds.
+		ProjectQuotaThresholdReached {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			threshold: Percent,
+		},
+		/// Quota thresholds of a project are changed.
+		QuotaThresholdsChanged { project_id: T::MassbitId, thresholds: Vec<Percent> },
+		/// Auto top-up of a project is changed, a zero amount disables it.
+		AutoTopUpChanged { project_id: T::MassbitId, amount: BalanceOf<T>, max_spend: BalanceOf<T> },
+		/// Project is deposited by its auto top-up.
+		ProjectAutoToppedUp {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			amount: BalanceOf<T>,
+			quota: u128,
+		},
+		/// Auto top-up of a project failed.
+		ProjectAutoTopUpFailed { project_id: T::MassbitId, error: DispatchError },
+		/// A regulator reported usage of a project, pending until the quorum agrees on it.
+		UsageReportSubmitted {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			regulator: T::AccountId,
+			period: UsagePeriod<T::BlockNumber>,
+			usage: u128,
+		},
+		/// Enough regulators agreed on the usage of a project and it is accepted, pending until
+		/// the end of the challenge period.
+		UsageReportFinalized {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+			usage: u128,
+		},
+		/// The consumer disputed pending usage of a project.
+		UsageDisputed {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+			consumer: T::AccountId,
+		},
+		/// A regulator voted on a usage dispute.
+		UsageDisputeVoted {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+			regulator: T::AccountId,
+			consumer_wins: bool,
+		},
+		/// A usage dispute was not resolved before its deadline. The usage is applied and both
+		/// sides get their bonds back.
+		UsageDisputeExpired {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+		},
+		/// A usage dispute is resolved, the usage is rolled back if the consumer wins.
+		UsageDisputeResolved {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+			consumer_wins: bool,
+		},
+		/// Pending usage of a project could not be applied, it is retried after another
+		/// challenge period.
+		UsageApplyFailed {
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+			error: DispatchError,
+		},
+		/// A usage report of a batch is processed, failed reports are rolled back.
+		UsageBatchItemProcessed { project_id: T::MassbitId, result: DispatchResult },
+		/// Project is closed and its unused quota is refunded to the consumer.
+		ProjectClosed { project_id: T::MassbitId, refund: BalanceOf<T> },
+		/// Part of the refund of a closed project could not be paid by `ProjectRefundAccount`
+		/// and has to be refunded by other means.
+		ProjectRefundFailed { project_id: T::MassbitId, amount: BalanceOf<T> },
+		/// Project is suspended.
+		ProjectSuspended { project_id: T::MassbitId, by: T::AccountId },
+		/// Suspended project is resumed.
+		ProjectResumed { project_id: T::MassbitId, by: T::AccountId },
+		/// Project is deleted along with its storage.
+		ProjectDeleted { project_id: T::MassbitId, by: T::AccountId },
+		/// Consumer offered to transfer a project to another account.
+		ProjectTransferRequested { project_id: T::MassbitId, from: T::AccountId, to: T::AccountId },
+		/// Pending transfer of a project is cancelled.
+		ProjectTransferCancelled { project_id: T::MassbitId },
+		/// Project is transferred to a new consumer.
+		ProjectTransferred { project_id: T::MassbitId, from: T::AccountId, to: T::AccountId },
+		/// Member is added to a project or its role is changed.
+		ProjectMemberAdded { project_id: T::MassbitId, member: T::AccountId, role: ProjectRole },
+		/// Member is removed from a project.
+		ProjectMemberRemoved { project_id: T::MassbitId, member: T::AccountId },
+		/// Metadata of a project is set.
+		ProjectMetadataSet { project_id: T::MassbitId, deposit: BalanceOf<T> },
+		/// Metadata of a project is cleared.
+		ProjectMetadataCleared { project_id: T::MassbitId },
+		/// API key is added to a project.
+		ApiKeyAdded {
+			project_id: T::MassbitId,
+			key_hash: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		},
+		/// API key of a project is revoked.
+		ApiKeyRevoked { project_id: T::MassbitId, key_hash: T::Hash },
+		/// Rate limit of a project set by an admin of the project is changed.
+		ProjectRateLimitChanged { project_id: T::MassbitId, limit: RateLimit },
+		/// Rate limit cap of a project set by a regulator is changed.
+		ProjectRateLimitCapChanged { project_id: T::MassbitId, limit: RateLimit },
+		/// API key of a project is replaced by a new one.
+		ApiKeyRotated {
+			project_id: T::MassbitId,
+			old_key_hash: T::Hash,
+			new_key_hash: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		},
 		/// A provider is registered.
 		ProviderRegistered {
 			provider_id: T::MassbitId,
@@ -108,6 +400,36 @@ pub mod pallet {
 		},
 		/// Provider is deposited and becomes activated.
 		ProviderActivated { provider_id: T::MassbitId, provider_type: ProviderType },
+		/// A self-registered provider waits for the approval of a regulator.
+		ProviderApprovalRequested { provider_id: T::MassbitId },
+		/// A self-registered provider is rejected by a regulator.
+		ProviderRejected { provider_id: T::MassbitId, by: T::AccountId },
+		/// A deactivated provider is activated again.
+		ProviderReactivated { provider_id: T::MassbitId, by: T::AccountId },
+		/// Operator offered to transfer a provider to another account.
+		ProviderTransferRequested {
+			provider_id: T::MassbitId,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
+		/// Pending transfer of a provider is cancelled.
+		ProviderTransferCancelled { provider_id: T::MassbitId },
+		/// Provider is transferred to a new operator.
+		ProviderTransferred { provider_id: T::MassbitId, from: T::AccountId, to: T::AccountId },
+		/// Metadata of a provider is set.
+		ProviderMetadataSet { provider_id: T::MassbitId, deposit: BalanceOf<T> },
+		/// Metadata of a provider is cleared.
+		ProviderMetadataCleared { provider_id: T::MassbitId },
+		/// A provider is warned for its first offence in the offence window.
+		ProviderWarned { provider_id: T::MassbitId, reason: ProviderDeactivateReason },
+		/// A provider is jailed for a repeated offence.
+		ProviderJailed {
+			provider_id: T::MassbitId,
+			until_block: T::BlockNumber,
+			reason: ProviderDeactivateReason,
+		},
+		/// A jailed provider is back in service.
+		ProviderUnjailed { provider_id: T::MassbitId },
 		/// A provider is deactivated by deregistration or reported offence by regulator.
 		ProviderDeactivated {
 			provider_id: T::MassbitId,
@@ -122,17 +444,239 @@ pub mod pallet {
 		RegulatorAdded { account_id: T::AccountId },
 		/// New regulator is removed.
 		RegulatorRemoved { account_id: T::AccountId },
+		/// Price of a single request is changed.
+		QuotaPriceChanged { price: BalanceOf<T> },
+		/// Price of a single request on a chain is changed, `None` falls back to the default.
+		ChainQuotaPriceChanged { chain_id: Vec<u8>, price: Option<BalanceOf<T>> },
+		/// Quorum required to accept usage reports is changed.
+		UsageQuorumChanged { threshold: u32, tolerance: Perbill },
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn projects)]
-	pub(super) type Projects<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::MassbitId, Project<AccountIdOf<T>, ChainId<T>>>;
+	pub(super) type Projects<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+	>;
+
+	/// Fractions of the quota at which `ProjectQuotaThresholdReached` is emitted, ascending.
+	#[pallet::storage]
+	#[pallet::getter(fn quota_thresholds)]
+	pub type QuotaThresholds<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		BoundedVec<Percent, T::MaxQuotaThresholds>,
+		ValueQuery,
+	>;
+
+	/// Auto top-up pre-authorized by the consumer of a project.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_top_up)]
+	pub type AutoTopUps<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, AutoTopUp<BalanceOf<T>>>;
+
+	/// Accounts allowed to manage a project on behalf of its consumer.
+	#[pallet::storage]
+	#[pallet::getter(fn project_members)]
+	pub type ProjectMembers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		BoundedVec<(T::AccountId, ProjectRole), T::MaxProjectMembers>,
+		ValueQuery,
+	>;
+
+	/// Rate limit of a project set by an admin of the project.
+	#[pallet::storage]
+	#[pallet::getter(fn project_rate_limit_of)]
+	pub type ProjectRateLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, RateLimit, ValueQuery>;
+
+	/// Rate limit cap of a project set by a regulator, e.g. according to its plan tier.
+	#[pallet::storage]
+	#[pallet::getter(fn project_rate_limit_cap)]
+	pub type ProjectRateLimitCaps<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, RateLimit, ValueQuery>;
+
+	/// Hashes of the API keys gateways accept for a project.
+	#[pallet::storage]
+	#[pallet::getter(fn project_api_keys)]
+	pub type ProjectApiKeys<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		BoundedVec<ApiKey<T::Hash, T::BlockNumber>, T::MaxApiKeys>,
+		ValueQuery,
+	>;
+
+	/// Metadata of a project.
+	#[pallet::storage]
+	pub type ProjectMetadataStore<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, ProjectMetadataOf<T>>;
+
+	/// Account that reserved the metadata deposit of a project and the deposit amount.
+	#[pallet::storage]
+	#[pallet::getter(fn project_metadata_deposit)]
+	pub type ProjectMetadataDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, (T::AccountId, BalanceOf<T>)>;
+
+	/// Bond reserved from the operator of a provider waiting for approval, staked once the
+	/// provider is approved.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_provider_bond)]
+	pub type PendingProviderBonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, BalanceOf<T>>;
+
+	/// Account a provider is offered to, until it accepts the transfer.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_provider_transfer)]
+	pub type PendingProviderTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, T::AccountId>;
+
+	/// Account a project is offered to, until it accepts the transfer.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_project_transfer)]
+	pub type PendingProjectTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, T::AccountId>;
+
+	/// Latest accepted usage reporting period of a project on a chain.
+	#[pallet::storage]
+	#[pallet::getter(fn last_usage_period)]
+	pub type LastUsagePeriod<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		Blake2_128Concat,
+		ChainId<T>,
+		UsagePeriod<T::BlockNumber>,
+	>;
+
+	/// Nonce used to derive project and provider ids on chain.
+	#[pallet::storage]
+	pub type MassbitIdNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Usage applied to a project during each dAPI staking era.
+	#[pallet::storage]
+	#[pallet::getter(fn project_usage_by_era)]
+	pub type ProjectUsageByEra<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		Twox64Concat,
+		EraIndex,
+		u128,
+		ValueQuery,
+	>;
+
+	/// Eras recorded in `ProjectUsageByEra` for each project, oldest first.
+	#[pallet::storage]
+	pub type ProjectUsageEras<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		BoundedVec<EraIndex, T::UsageHistoryDepth>,
+		ValueQuery,
+	>;
+
+	/// Usage reports waiting for the quorum, keyed by project, chain and period.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_usage_reports)]
+	pub type PendingUsageReports<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		Blake2_128Concat,
+		(ChainId<T>, UsagePeriod<T::BlockNumber>),
+		Vec<UsageReport<T::AccountId, BalanceOf<T>>>,
+		ValueQuery,
+	>;
+
+	/// Usage accepted by the quorum that can still be disputed, keyed by project, chain and
+	/// period.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_usage)]
+	pub type PendingUsages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		Blake2_128Concat,
+		(ChainId<T>, UsagePeriod<T::BlockNumber>),
+		PendingUsage<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+	>;
+
+	/// Pending usage to apply at the end of its challenge period or at its dispute deadline,
+	/// keyed by block number.
+	#[pallet::storage]
+	pub type UsageChallengeEnds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Vec<(T::MassbitId, ChainId<T>, UsagePeriod<T::BlockNumber>)>,
+		ValueQuery,
+	>;
+
+	/// Offences of each provider within its offence window.
+	#[pallet::storage]
+	#[pallet::getter(fn provider_offences)]
+	pub type ProviderOffenceCounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, ProviderOffences<T::BlockNumber>>;
+
+	/// Providers deactivated for reaching `MaxOffences`, only a regulator can reactivate them.
+	#[pallet::storage]
+	pub type OffenceDeactivations<T: Config> = StorageMap<_, Blake2_128Concat, T::MassbitId, ()>;
+
+	/// Jailed providers to release, keyed by block number.
+	#[pallet::storage]
+	pub type JailReleases<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<T::MassbitId>, ValueQuery>;
+
+	#[pallet::type_value]
+	pub fn UsageQuorumOnEmpty() -> UsageQuorum {
+		UsageQuorum { threshold: 1, tolerance: Perbill::zero() }
+	}
+
+	/// Number of regulators that must report matching usage before it is accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn usage_quorum)]
+	pub type UsageQuorumConfig<T> = StorageValue<_, UsageQuorum, ValueQuery, UsageQuorumOnEmpty>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn providers)]
-	pub(super) type Providers<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::MassbitId, Provider<AccountIdOf<T>, ChainId<T>>>;
+	pub(super) type Providers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MassbitId,
+		Provider<AccountIdOf<T>, ChainId<T>, T::BlockNumber>,
+	>;
+
+	/// Projects owned by each consumer.
+	#[pallet::storage]
+	pub type ProjectsByConsumer<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::MassbitId, ()>;
+
+	/// Providers run by each operator.
+	#[pallet::storage]
+	pub type ProvidersByOperator<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::MassbitId, ()>;
+
+	/// Active providers serving each chain.
+	#[pallet::storage]
+	pub type ActiveProvidersByChain<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ChainId<T>, Blake2_128Concat, T::MassbitId, ()>;
+
+	/// Metadata of a provider.
+	#[pallet::storage]
+	pub type ProviderMetadataStore<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, ProviderMetadataOf<T>>;
+
+	/// Account that reserved the metadata deposit of a provider and the deposit amount.
+	#[pallet::storage]
+	#[pallet::getter(fn provider_metadata_deposit)]
+	pub type ProviderMetadataDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MassbitId, (T::AccountId, BalanceOf<T>)>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn regulators)]
@@ -142,6 +686,23 @@ pub mod pallet {
 	#[pallet::getter(fn chain_ids)]
 	pub type ChainIds<T: Config> = StorageValue<_, BTreeSet<ChainId<T>>, ValueQuery>;
 
+	#[pallet::type_value]
+	pub fn QuotaPriceOnEmpty<T: Config>() -> BalanceOf<T> {
+		T::DefaultQuotaPrice::get()
+	}
+
+	/// Price of a single request, used to convert project deposits into quota.
+	#[pallet::storage]
+	#[pallet::getter(fn quota_price)]
+	pub type QuotaPrice<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery, QuotaPriceOnEmpty<T>>;
+
+	/// Price of a single request for chains that override the default `QuotaPrice`.
+	#[pallet::storage]
+	#[pallet::getter(fn chain_quota_price)]
+	pub type ChainQuotaPrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, ChainId<T>, BalanceOf<T>>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub regulators: Vec<T::AccountId>,
@@ -160,6 +721,64 @@ pub mod pallet {
 			let regulators =
 				&self.regulators.iter().map(|r| r.clone()).collect::<BTreeSet<T::AccountId>>();
 			Regulators::<T>::put(&regulators);
+
+			// The escrow account is shared by all projects, it must never be reaped.
+			let _ = T::Currency::make_free_balance_be(
+				&Pallet::<T>::escrow_account_id(),
+				T::Currency::minimum_balance(),
+			);
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut consumed_weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut due = UsageChallengeEnds::<T>::take(now);
+			let limit = T::MaxUsageAppliedPerBlock::get() as usize;
+			if due.len() > limit {
+				// Carried over usages are settled before the ones due in the next block.
+				let mut carried = due.split_off(limit);
+				UsageChallengeEnds::<T>::mutate(now.saturating_add(One::one()), |next| {
+					carried.append(next);
+					*next = carried;
+				});
+				consumed_weight =
+					consumed_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			}
+			for (project_id, chain_id, period) in due {
+				consumed_weight = consumed_weight.saturating_add(Self::apply_pending_usage(
+					&project_id,
+					&chain_id,
+					period,
+					now,
+				));
+			}
+			for provider_id in JailReleases::<T>::take(now) {
+				consumed_weight = consumed_weight
+					.saturating_add(Self::release_jailed_provider(&provider_id, now));
+			}
+			consumed_weight
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+
+		fn integrity_test() {
+			assert!(!T::JailDuration::get().is_zero(), "`JailDuration` must not be zero");
+			assert!(
+				!T::UsageHistoryDepth::get().is_zero(),
+				"`UsageHistoryDepth` must not be zero"
+			);
+			assert!(
+				!T::MaxUsageAppliedPerBlock::get().is_zero(),
+				"`MaxUsageAppliedPerBlock` must not be zero"
+			);
+			assert!(
+				!T::UsageDisputePeriod::get().is_zero(),
+				"`UsageDisputePeriod` must not be zero"
+			);
 		}
 	}
 
@@ -174,276 +793,2253 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let consumer = ensure_signed(origin)?;
 
-			ensure!(!<Projects<T>>::contains_key(&project_id), Error::<T>::AlreadyExist);
-
-			let bounded_chain_id: BoundedVec<u8, T::ChainIdMaxLength> =
-				chain_id.clone().try_into().map_err(|_| Error::<T>::BadChainId)?;
-			ensure!(Self::chain_ids().contains(&bounded_chain_id), Error::<T>::NotExist);
-
-			let payment = T::Currency::withdraw(
-				&consumer,
-				deposit,
-				WithdrawReasons::TRANSFER,
-				ExistenceRequirement::KeepAlive,
-			)?;
-			T::OnProjectPayment::on_unbalanced(payment);
-
-			let quota = Self::calculate_quota(deposit);
-			let project =
-				Project { consumer: consumer.clone(), chain_id: bounded_chain_id, quota, usage: 0 };
+			Self::do_register_project(consumer, project_id, chain_id, deposit)?;
+			Ok(().into())
+		}
 
-			<Projects<T>>::insert(&project_id, project);
+		/// Register a project under an id derived on chain, returned in `ProjectRegistered`.
+		#[pallet::weight(T::WeightInfo::create_project())]
+		pub fn create_project(
+			origin: OriginFor<T>,
+			chain_id: Vec<u8>,
+			#[pallet::compact] deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let consumer = ensure_signed(origin)?;
 
-			Self::deposit_event(Event::ProjectRegistered { project_id, consumer, chain_id, quota });
+			let project_id = Self::generate_massbit_id(&consumer)?;
+			Self::do_register_project(consumer, project_id, chain_id, deposit)?;
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::deposit_project())]
-		pub fn deposit_project(
+		/// Add a chain to a project with its own quota bought by `deposit`, requires the `Admin`
+		/// role.
+		#[pallet::weight(T::WeightInfo::add_project_chain())]
+		pub fn add_project_chain(
 			origin: OriginFor<T>,
 			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
 			#[pallet::compact] deposit: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
-			let consumer = ensure_signed(origin)?;
+			let who = ensure_signed(origin)?;
 
 			let mut project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
 
-			let payment = T::Currency::withdraw(
-				&consumer,
-				deposit,
-				WithdrawReasons::TRANSFER,
-				ExistenceRequirement::KeepAlive,
-			)?;
-			T::OnProjectPayment::on_unbalanced(payment);
+			let bounded_chain_id = Self::bounded_chain_id(chain_id.clone())?;
+			ensure!(Self::chain_ids().contains(&bounded_chain_id), Error::<T>::NotExist);
+			ensure!(project.chain(&bounded_chain_id).is_none(), Error::<T>::AlreadyExist);
+			ensure!(
+				(project.chains.len() as u32) < T::MaxProjectChains::get(),
+				Error::<T>::TooManyProjectChains
+			);
 
-			let quota = project.quota.saturating_add(Self::calculate_quota(deposit));
-			project.quota = quota;
+			let escrow = Self::take_deposit(&who, deposit)?;
+
+			let quota_price = Self::quota_price_of(&bounded_chain_id);
+			let quota = Self::calculate_quota(deposit, quota_price);
+			project.chains.push(ChainQuota {
+				chain_id: bounded_chain_id,
+				quota,
+				usage: 0,
+				quota_price,
+				escrow,
+				paid: deposit.saturating_sub(escrow),
+			});
+			if project.state == ProjectState::Exhausted && !project.is_exhausted() {
+				project.state = ProjectState::Active;
+			}
 
-			<Projects<T>>::insert(&project_id, project);
+			Projects::<T>::insert(&project_id, project);
 
-			Self::deposit_event(Event::ProjectDeposited { project_id, quota });
+			Self::deposit_event(Event::ProjectChainAdded { project_id, chain_id, quota });
 			Ok(().into())
 		}
 
-		#[pallet::weight(100)]
+		/// Deposit to the chain a project was registered with, requires the `Depositor` role.
+		///
+		/// Use `deposit_project_chain` to deposit to a chain added later.
+		#[pallet::weight(T::WeightInfo::deposit_project())]
+		pub fn deposit_project(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			#[pallet::compact] deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let depositor = ensure_signed(origin)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			let chain_id = project
+				.chains
+				.first()
+				.map(|chain| chain.chain_id.clone())
+				.ok_or(Error::<T>::NotExist)?;
+			Self::deposit_project_to(depositor, project_id, project, chain_id, deposit)
+		}
+
+		/// Deposit to a chain of a project, requires the `Depositor` role.
+		#[pallet::weight(T::WeightInfo::deposit_project())]
+		pub fn deposit_project_chain(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			#[pallet::compact] deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let depositor = ensure_signed(origin)?;
+			let bounded_chain_id = Self::bounded_chain_id(chain_id)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			Self::deposit_project_to(depositor, project_id, project, bounded_chain_id, deposit)
+		}
+
+		#[pallet::weight(
+			T::WeightInfo::submit_project_usage()
+				.saturating_add(Pallet::<T>::immediate_usage_weight(1))
+		)]
+		#[transactional]
 		pub fn submit_project_usage(
 			origin: OriginFor<T>,
 			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
 			usage: u128,
+			period: UsagePeriod<T::BlockNumber>,
 		) -> DispatchResultWithPostInfo {
 			let regulator = ensure_signed(origin)?;
 			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
 
-			let mut project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
-			project.usage = project.usage.saturating_add(usage).min(project.quota);
-			if project.usage == project.quota {
-				Self::deposit_event(Event::ProjectReachedQuota { project_id: project_id.clone() });
-			};
-
-			Projects::<T>::insert(&project_id, project);
+			let chain_id = Self::bounded_chain_id(chain_id)?;
+			Self::do_submit_project_usage(&regulator, &project_id, &chain_id, usage, period)?;
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(100)]
-		pub fn register_provider(
+		/// Submit usage of many projects at once.
+		///
+		/// Every report is applied on its own: a failed report is rolled back and does not affect
+		/// the others. The outcome of each report is emitted as `UsageBatchItemProcessed`.
+		#[pallet::weight(
+			T::WeightInfo::submit_project_usage_batch(usages.len() as u32)
+				.saturating_add(Pallet::<T>::immediate_usage_weight(usages.len() as u32))
+		)]
+		pub fn submit_project_usage_batch(
 			origin: OriginFor<T>,
-			provider_id: T::MassbitId,
-			provider_type: ProviderType,
-			operator: T::AccountId,
-			chain_id: Vec<u8>,
+			usages: Vec<(T::MassbitId, Vec<u8>, u128, UsagePeriod<T::BlockNumber>)>,
 		) -> DispatchResultWithPostInfo {
 			let regulator = ensure_signed(origin)?;
 			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
-
-			ensure!(!<Providers<T>>::contains_key(&provider_id), Error::<T>::AlreadyExist);
-
-			let bounded_chain_id: BoundedVec<u8, T::ChainIdMaxLength> =
-				chain_id.clone().try_into().map_err(|_| Error::<T>::BadChainId)?;
-			ensure!(Self::chain_ids().contains(&bounded_chain_id), Error::<T>::NotExist);
-
-			<Providers<T>>::insert(
-				&provider_id,
-				Provider {
-					provider_type,
-					operator: operator.clone(),
-					chain_id: bounded_chain_id,
-					state: ProviderState::Registered,
-				},
+			ensure!(
+				usages.len() as u32 <= T::MaxUsageBatchSize::get(),
+				Error::<T>::TooManyUsageReports
 			);
 
-			Self::deposit_event(Event::ProviderRegistered {
-				provider_id,
-				provider_type,
-				operator,
-				chain_id,
-			});
+			for (project_id, chain_id, usage, period) in usages {
+				let result = with_transaction(|| {
+					let result = Self::bounded_chain_id(chain_id).and_then(|chain_id| {
+						Self::do_submit_project_usage(
+							&regulator,
+							&project_id,
+							&chain_id,
+							usage,
+							period,
+						)
+					});
+					if result.is_ok() {
+						TransactionOutcome::Commit(result)
+					} else {
+						TransactionOutcome::Rollback(result)
+					}
+				});
+
+				Self::deposit_event(Event::UsageBatchItemProcessed { project_id, result });
+			}
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(100)]
-		pub fn deposit_provider(
+		#[pallet::weight(T::WeightInfo::close_project())]
+		pub fn close_project(
 			origin: OriginFor<T>,
-			provider_id: T::MassbitId,
-			#[pallet::compact] deposit: BalanceOf<T>,
+			project_id: T::MassbitId,
 		) -> DispatchResultWithPostInfo {
-			let operator = ensure_signed(origin)?;
-
-			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
-			ensure!(provider.operator == operator, Error::<T>::NotOwner);
-			ensure!(provider.state == ProviderState::Registered, Error::<T>::InvalidProviderState);
-
-			T::DapiStaking::register(operator.clone(), provider_id.clone(), deposit)?;
+			let consumer = ensure_signed(origin)?;
 
-			provider.state = ProviderState::Active;
-			Providers::<T>::insert(&provider_id, provider.clone());
+			let mut project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(project.consumer == consumer, Error::<T>::NotOwner);
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
 
-			Self::deposit_event(Event::ProviderActivated {
-				provider_id,
-				provider_type: provider.provider_type,
-			});
+			Self::do_close_project(&project_id, &mut project)?;
+			Projects::<T>::insert(&project_id, project);
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::unregister_provider())]
-		pub fn unregister_provider(
+		/// Offer a project to another account, which becomes its consumer once it accepts.
+		///
+		/// A new offer replaces the pending one.
+		#[pallet::weight(T::WeightInfo::transfer_project())]
+		pub fn transfer_project(
 			origin: OriginFor<T>,
-			provider_id: T::MassbitId,
+			project_id: T::MassbitId,
+			new_consumer: T::AccountId,
 		) -> DispatchResultWithPostInfo {
-			let account = ensure_signed(origin)?;
-
-			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
-			ensure!(provider.operator == account, Error::<T>::NotOwner);
-			ensure!(provider.state == ProviderState::Active, Error::<T>::InactiveProvider);
+			let consumer = ensure_signed(origin)?;
 
-			T::DapiStaking::unregister(provider_id.clone())?;
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(project.consumer == consumer, Error::<T>::NotOwner);
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
 
-			provider.state = ProviderState::InActive;
-			Providers::<T>::insert(&provider_id, provider.clone());
+			PendingProjectTransfers::<T>::insert(&project_id, &new_consumer);
 
-			Self::deposit_event(Event::<T>::ProviderDeactivated {
-				provider_id,
-				provider_type: provider.provider_type,
-				reason: ProviderDeactivateReason::UnRegistered,
+			Self::deposit_event(Event::ProjectTransferRequested {
+				project_id,
+				from: consumer,
+				to: new_consumer,
 			});
-
 			Ok(().into())
 		}
 
-		#[pallet::weight(100)]
+		/// Cancel the pending transfer of a project.
+		#[pallet::weight(T::WeightInfo::cancel_project_transfer())]
+		pub fn cancel_project_transfer(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let consumer = ensure_signed(origin)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(project.consumer == consumer, Error::<T>::NotOwner);
+			ensure!(
+				PendingProjectTransfers::<T>::take(&project_id).is_some(),
+				Error::<T>::NotExist
+			);
+
+			Self::deposit_event(Event::ProjectTransferCancelled { project_id });
+			Ok(().into())
+		}
+
+		/// Accept a project offered by its consumer.
+		///
+		/// The members, API keys and auto top-up authorized by the previous consumer are removed.
+		#[pallet::weight(T::WeightInfo::accept_project_transfer())]
+		pub fn accept_project_transfer(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let new_consumer = ensure_signed(origin)?;
+
+			ensure!(
+				Self::pending_project_transfer(&project_id).as_ref() == Some(&new_consumer),
+				Error::<T>::PermissionDenied
+			);
+			let mut project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
+
+			let consumer = sp_std::mem::replace(&mut project.consumer, new_consumer.clone());
+			Projects::<T>::insert(&project_id, project);
+			ProjectsByConsumer::<T>::remove(&consumer, &project_id);
+			ProjectsByConsumer::<T>::insert(&new_consumer, &project_id, ());
+			PendingProjectTransfers::<T>::remove(&project_id);
+			ProjectMembers::<T>::remove(&project_id);
+			ProjectApiKeys::<T>::remove(&project_id);
+			AutoTopUps::<T>::remove(&project_id);
+
+			Self::deposit_event(Event::ProjectTransferred {
+				project_id,
+				from: consumer,
+				to: new_consumer,
+			});
+			Ok(().into())
+		}
+
+		/// Suspend a project so that gateways stop serving it.
+		///
+		/// Can be called by an admin of the project or a regulator. A regulator can also take over
+		/// a suspension made by an admin, so that the admins can't resume the project.
+		#[pallet::weight(T::WeightInfo::suspend_project())]
+		pub fn suspend_project(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			let by_regulator = Self::regulators().contains(&who);
+			ensure!(
+				by_regulator || Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(
+				match project.state {
+					ProjectState::Active | ProjectState::Exhausted => true,
+					ProjectState::Suspended { by_regulator: false } => by_regulator,
+					_ => false,
+				},
+				Error::<T>::InvalidProjectState
+			);
+
+			project.state = ProjectState::Suspended { by_regulator };
+			Projects::<T>::insert(&project_id, project);
+
+			Self::deposit_event(Event::ProjectSuspended { project_id, by: who });
+			Ok(().into())
+		}
+
+		/// Resume a suspended project.
+		///
+		/// Can be called by a regulator, or by an admin of the project if it was not suspended by
+		/// a regulator.
+		#[pallet::weight(T::WeightInfo::resume_project())]
+		pub fn resume_project(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			let by_regulator = match project.state {
+				ProjectState::Suspended { by_regulator } => by_regulator,
+				_ => return Err(Error::<T>::InvalidProjectState.into()),
+			};
+			ensure!(
+				Self::regulators().contains(&who) ||
+					(!by_regulator &&
+						Self::has_role(&project_id, &project, &who, ProjectRole::Admin)),
+				Error::<T>::PermissionDenied
+			);
+
+			project.state =
+				if project.is_exhausted() { ProjectState::Exhausted } else { ProjectState::Active };
+			Projects::<T>::insert(&project_id, project);
+
+			Self::deposit_event(Event::ProjectResumed { project_id, by: who });
+			Ok(().into())
+		}
+
+		/// Delete a project and all of its storage, closing it first if needed.
+		///
+		/// Can be called by the consumer of the project or a regulator.
+		#[pallet::weight(T::WeightInfo::delete_project(T::UsageHistoryDepth::get()))]
+		pub fn delete_project(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			Self::ensure_consumer_or_regulator(&who, &project)?;
+
+			if project.state != ProjectState::Closed {
+				Self::do_close_project(&project_id, &mut project)?;
+			}
+
+			Projects::<T>::remove(&project_id);
+			ProjectsByConsumer::<T>::remove(&project.consumer, &project_id);
+			ProjectMembers::<T>::remove(&project_id);
+			ProjectApiKeys::<T>::remove(&project_id);
+			ProjectRateLimits::<T>::remove(&project_id);
+			ProjectRateLimitCaps::<T>::remove(&project_id);
+			ProjectMetadataStore::<T>::remove(&project_id);
+			Self::release_metadata_deposit(&project_id);
+			for era in ProjectUsageEras::<T>::take(&project_id) {
+				ProjectUsageByEra::<T>::remove(&project_id, era);
+			}
+
+			Self::deposit_event(Event::ProjectDeleted { project_id, by: who });
+			Ok(().into())
+		}
+
+		#[pallet::weight(100)]
+		pub fn register_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+			provider_type: ProviderType,
+			operator: T::AccountId,
+			chain_id: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let regulator = ensure_signed(origin)?;
+			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
+
+			Self::do_register_provider(
+				provider_id,
+				provider_type,
+				operator,
+				chain_id,
+				ProviderState::Registered,
+			)?;
+			Ok(().into())
+		}
+
+		/// Register a provider operated by the caller, locking `bond` through dAPI staking.
+		///
+		/// The provider is active right away unless `RequireProviderApproval` is set, in which
+		/// case `bond` stays reserved until a regulator approves the provider, staking the bond,
+		/// or rejects it, returning the bond.
+		#[pallet::weight(T::WeightInfo::self_register_provider())]
+		#[transactional]
+		pub fn self_register_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+			provider_type: ProviderType,
+			chain_id: Vec<u8>,
+			#[pallet::compact] bond: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+
+			let state = if T::RequireProviderApproval::get() {
+				ProviderState::PendingApproval
+			} else {
+				ProviderState::Active
+			};
+			Self::do_register_provider(
+				provider_id.clone(),
+				provider_type,
+				operator.clone(),
+				chain_id,
+				state,
+			)?;
+
+			if state == ProviderState::Active {
+				T::DapiStaking::register(operator, provider_id.clone(), bond)?;
+				Self::deposit_event(Event::ProviderActivated { provider_id, provider_type });
+			} else {
+				// Checked now so that the provider can't be stuck waiting for an approval that
+				// would fail.
+				ensure!(
+					bond >= T::DapiStaking::minimum_register_deposit(),
+					Error::<T>::InsufficientBond
+				);
+				T::Currency::reserve(&operator, bond)?;
+				PendingProviderBonds::<T>::insert(&provider_id, bond);
+				Self::deposit_event(Event::ProviderApprovalRequested { provider_id });
+			}
+			Ok(().into())
+		}
+
+		/// Approve a self-registered provider so that it becomes active, staking its bond through
+		/// dAPI staking.
+		#[pallet::weight(T::WeightInfo::approve_provider())]
+		#[transactional]
+		pub fn approve_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let regulator = ensure_signed(origin)?;
+			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
+
+			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				provider.state == ProviderState::PendingApproval,
+				Error::<T>::InvalidProviderState
+			);
+
+			let bond = PendingProviderBonds::<T>::take(&provider_id).unwrap_or_default();
+			T::Currency::unreserve(&provider.operator, bond);
+			T::DapiStaking::register(provider.operator.clone(), provider_id.clone(), bond)?;
+
+			provider.state = ProviderState::Active;
+			Providers::<T>::insert(&provider_id, provider.clone());
+			ActiveProvidersByChain::<T>::insert(&provider.chain_id, &provider_id, ());
+
+			Self::deposit_event(Event::ProviderActivated {
+				provider_id,
+				provider_type: provider.provider_type,
+			});
+			Ok(().into())
+		}
+
+		/// Reject a self-registered provider, returning its bond to the operator.
+		#[pallet::weight(T::WeightInfo::reject_provider())]
+		pub fn reject_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let regulator = ensure_signed(origin)?;
+			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
+
+			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				provider.state == ProviderState::PendingApproval,
+				Error::<T>::InvalidProviderState
+			);
+
+			let bond = PendingProviderBonds::<T>::take(&provider_id).unwrap_or_default();
+			T::Currency::unreserve(&provider.operator, bond);
+
+			provider.state = ProviderState::Rejected;
+			Providers::<T>::insert(&provider_id, provider);
+
+			Self::deposit_event(Event::ProviderRejected { provider_id, by: regulator });
+			Ok(().into())
+		}
+
+		/// Register a provider under an id derived on chain, returned in `ProviderRegistered`.
+		#[pallet::weight(T::WeightInfo::create_provider())]
+		pub fn create_provider(
+			origin: OriginFor<T>,
+			provider_type: ProviderType,
+			operator: T::AccountId,
+			chain_id: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let regulator = ensure_signed(origin)?;
+			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
+
+			let provider_id = Self::generate_massbit_id(&regulator)?;
+			Self::do_register_provider(
+				provider_id,
+				provider_type,
+				operator,
+				chain_id,
+				ProviderState::Registered,
+			)?;
+			Ok(().into())
+		}
+
+		#[pallet::weight(100)]
+		pub fn deposit_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+			#[pallet::compact] deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+
+			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.operator == operator, Error::<T>::NotOwner);
+			ensure!(provider.state == ProviderState::Registered, Error::<T>::InvalidProviderState);
+
+			T::DapiStaking::register(operator.clone(), provider_id.clone(), deposit)?;
+
+			provider.state = ProviderState::Active;
+			Providers::<T>::insert(&provider_id, provider.clone());
+			ActiveProvidersByChain::<T>::insert(&provider.chain_id, &provider_id, ());
+
+			Self::deposit_event(Event::ProviderActivated {
+				provider_id,
+				provider_type: provider.provider_type,
+			});
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::unregister_provider())]
+		pub fn unregister_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let account = ensure_signed(origin)?;
+
+			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.operator == account, Error::<T>::NotOwner);
+			ensure!(provider.state == ProviderState::Active, Error::<T>::InactiveProvider);
+
+			T::DapiStaking::unregister(provider_id.clone())?;
+
+			provider.state = ProviderState::InActive;
+			Providers::<T>::insert(&provider_id, provider.clone());
+			ActiveProvidersByChain::<T>::remove(&provider.chain_id, &provider_id);
+
+			Self::deposit_event(Event::<T>::ProviderDeactivated {
+				provider_id,
+				provider_type: provider.provider_type,
+				reason: ProviderDeactivateReason::UnRegistered,
+			});
+
+			Ok(().into())
+		}
+
+		/// Report an offence of a provider.
+		///
+		/// The first offence within the offence window is a warning, following ones jail the
+		/// provider for `JailDuration` blocks. Once `MaxOffences` is reached the provider is
+		/// deactivated until a regulator reactivates it, and its operator and stakers slashed
+		/// accordingly.
+		#[pallet::weight(
+			T::WeightInfo::report_provider_offence().saturating_add(T::DapiStaking::slash_weight())
+		)]
+		#[transactional]
 		pub fn report_provider_offence(
 			origin: OriginFor<T>,
-			provider_id: T::MassbitId,
-			reason: ProviderDeactivateReason,
+			provider_id: T::MassbitId,
+			reason: ProviderDeactivateReason,
+		) -> DispatchResultWithPostInfo {
+			let regulator = ensure_signed(origin)?;
+			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
+
+			let mut provider = Self::providers(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.state == ProviderState::Active, Error::<T>::InvalidProviderState);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut offences = Self::provider_offences(&provider_id)
+				.filter(|offences| now < offences.since.saturating_add(T::OffenceWindow::get()))
+				.unwrap_or(ProviderOffences { count: 0, since: now });
+			offences.count = offences.count.saturating_add(1);
+
+			if offences.count < T::MaxOffences::get() {
+				ProviderOffenceCounts::<T>::insert(&provider_id, offences);
+				if offences.count == 1 {
+					Self::deposit_event(Event::<T>::ProviderWarned { provider_id, reason });
+				} else {
+					T::DapiStaking::jail(provider_id.clone())?;
+
+					let until_block = now.saturating_add(T::JailDuration::get());
+					provider.state = ProviderState::Jailed { until_block };
+					Providers::<T>::insert(&provider_id, provider.clone());
+					ActiveProvidersByChain::<T>::remove(&provider.chain_id, &provider_id);
+					JailReleases::<T>::append(until_block, &provider_id);
+
+					Self::deposit_event(Event::<T>::ProviderJailed {
+						provider_id,
+						until_block,
+						reason,
+					});
+				}
+				return Ok(().into())
+			}
+
+			T::DapiStaking::unregister(provider_id.clone())?;
+			T::DapiStaking::slash(provider_id.clone(), reason)?;
+
+			provider.state = ProviderState::InActive;
+			Providers::<T>::insert(&provider_id, provider.clone());
+			ActiveProvidersByChain::<T>::remove(&provider.chain_id, &provider_id);
+			ProviderOffenceCounts::<T>::remove(&provider_id);
+			OffenceDeactivations::<T>::insert(&provider_id, ());
+
+			Self::deposit_event(Event::<T>::ProviderDeactivated {
+				provider_id,
+				provider_type: provider.provider_type,
+				reason,
+			});
+
+			Ok(().into())
+		}
+
+		/// Offer a provider to another account, which becomes its operator once it accepts.
+		///
+		/// A new offer replaces the pending one.
+		#[pallet::weight(T::WeightInfo::transfer_provider())]
+		pub fn transfer_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+			new_operator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+
+			let provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.operator == operator, Error::<T>::NotOwner);
+			ensure!(
+				!matches!(provider.state, ProviderState::PendingApproval | ProviderState::Rejected),
+				Error::<T>::InvalidProviderState
+			);
+
+			PendingProviderTransfers::<T>::insert(&provider_id, &new_operator);
+
+			Self::deposit_event(Event::ProviderTransferRequested {
+				provider_id,
+				from: operator,
+				to: new_operator,
+			});
+			Ok(().into())
+		}
+
+		/// Cancel the pending transfer of a provider.
+		#[pallet::weight(T::WeightInfo::cancel_provider_transfer())]
+		pub fn cancel_provider_transfer(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+
+			let provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.operator == operator, Error::<T>::NotOwner);
+			ensure!(
+				PendingProviderTransfers::<T>::take(&provider_id).is_some(),
+				Error::<T>::NotExist
+			);
+
+			Self::deposit_event(Event::ProviderTransferCancelled { provider_id });
+			Ok(().into())
+		}
+
+		/// Accept a provider offered by its operator.
+		///
+		/// The registration deposit and self-stake of the previous operator are moved to the new
+		/// one in dAPI staking, which is not possible while a slash of the provider is deferred.
+		#[pallet::weight(T::WeightInfo::accept_provider_transfer())]
+		#[transactional]
+		pub fn accept_provider_transfer(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let new_operator = ensure_signed(origin)?;
+
+			ensure!(
+				Self::pending_provider_transfer(&provider_id).as_ref() == Some(&new_operator),
+				Error::<T>::PermissionDenied
+			);
+			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				!matches!(provider.state, ProviderState::PendingApproval | ProviderState::Rejected),
+				Error::<T>::InvalidProviderState
+			);
+
+			// Providers that were never deposited are not known to dAPI staking.
+			if provider.state != ProviderState::Registered {
+				T::DapiStaking::change_operator(provider_id.clone(), new_operator.clone())?;
+			}
+
+			let operator = sp_std::mem::replace(&mut provider.operator, new_operator.clone());
+			Providers::<T>::insert(&provider_id, provider);
+			ProvidersByOperator::<T>::remove(&operator, &provider_id);
+			ProvidersByOperator::<T>::insert(&new_operator, &provider_id, ());
+			PendingProviderTransfers::<T>::remove(&provider_id);
+
+			Self::deposit_event(Event::ProviderTransferred {
+				provider_id,
+				from: operator,
+				to: new_operator,
+			});
+			Ok(().into())
+		}
+
+		/// Reactivate a deactivated provider.
+		///
+		/// The operator has to stake a fresh `deposit` through dAPI staking, a regulator can
+		/// approve the reactivation without one. A provider deactivated for its offences can
+		/// only be reactivated by a regulator. Stakes from before the deactivation that were not
+		/// withdrawn are kept.
+		#[pallet::weight(T::WeightInfo::reactivate_provider())]
+		#[transactional]
+		pub fn reactivate_provider(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+			#[pallet::compact] deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.state == ProviderState::InActive, Error::<T>::InvalidProviderState);
+
+			let deposit = if OffenceDeactivations::<T>::contains_key(&provider_id) {
+				ensure!(Self::regulators().contains(&who), Error::<T>::PermissionDenied);
+				Zero::zero()
+			} else if provider.operator == who && !deposit.is_zero() {
+				deposit
+			} else if Self::regulators().contains(&who) {
+				Zero::zero()
+			} else if provider.operator == who {
+				return Err(Error::<T>::NoReactivationDeposit.into())
+			} else {
+				return Err(Error::<T>::PermissionDenied.into())
+			};
+
+			T::DapiStaking::reactivate(provider.operator.clone(), provider_id.clone(), deposit)?;
+
+			provider.state = ProviderState::Active;
+			Providers::<T>::insert(&provider_id, provider.clone());
+			OffenceDeactivations::<T>::remove(&provider_id);
+			ActiveProvidersByChain::<T>::insert(&provider.chain_id, &provider_id, ());
+
+			Self::deposit_event(Event::ProviderReactivated { provider_id, by: who });
+			Ok(().into())
+		}
+
+		/// Set the metadata of a provider, requires to be its operator.
+		///
+		/// A deposit proportional to the size of the metadata is reserved from the operator, the
+		/// deposit of the previous metadata is returned.
+		#[pallet::weight(T::WeightInfo::set_provider_metadata())]
+		#[transactional]
+		pub fn set_provider_metadata(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+			region: Vec<u8>,
+			endpoint: Vec<u8>,
+			client: Vec<u8>,
+			capabilities: ProviderCapabilities,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+
+			let provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.operator == operator, Error::<T>::NotOwner);
+
+			let bytes = region.len() + endpoint.len() + client.len();
+			let metadata = ProviderMetadata {
+				region: region.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+				endpoint: endpoint.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+				client: client.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+				capabilities,
+			};
+
+			let deposit = T::MetadataDepositBase::get().saturating_add(
+				T::MetadataDepositPerByte::get().saturating_mul(bytes.saturated_into()),
+			);
+			Self::release_provider_metadata_deposit(&provider_id);
+			T::Currency::reserve(&operator, deposit)?;
+
+			ProviderMetadataStore::<T>::insert(&provider_id, metadata);
+			ProviderMetadataDeposits::<T>::insert(&provider_id, (operator, deposit));
+
+			Self::deposit_event(Event::ProviderMetadataSet { provider_id, deposit });
+			Ok(().into())
+		}
+
+		/// Clear the metadata of a provider and return its deposit, requires to be its operator.
+		#[pallet::weight(T::WeightInfo::clear_provider_metadata())]
+		pub fn clear_provider_metadata(
+			origin: OriginFor<T>,
+			provider_id: T::MassbitId,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+
+			let provider = Providers::<T>::get(&provider_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(provider.operator == operator, Error::<T>::NotOwner);
+			ensure!(ProviderMetadataStore::<T>::contains_key(&provider_id), Error::<T>::NotExist);
+
+			ProviderMetadataStore::<T>::remove(&provider_id);
+			Self::release_provider_metadata_deposit(&provider_id);
+
+			Self::deposit_event(Event::ProviderMetadataCleared { provider_id });
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::add_regulator())]
+		pub fn add_regulator(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateRegulatorOrigin::ensure_origin(origin)?;
+
+			let mut regulators = Regulators::<T>::get();
+			ensure!(!regulators.contains(&account_id), Error::<T>::AlreadyExist);
+
+			regulators.insert(account_id.clone());
+			Regulators::<T>::put(&regulators);
+
+			Self::deposit_event(Event::RegulatorAdded { account_id });
+
+			Ok(().into())
+		}
+
+		/// Remove a regulator.
+		///
+		/// If fewer regulators than the usage quorum threshold are left, the threshold is lowered
+		/// to the number of regulators left, but not below one.
+		#[pallet::weight(T::WeightInfo::remove_regulator())]
+		pub fn remove_regulator(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateRegulatorOrigin::ensure_origin(origin)?;
+
+			let mut regulators = Regulators::<T>::get();
+			ensure!(regulators.contains(&account_id), Error::<T>::NotExist);
+
+			regulators.remove(&account_id);
+			Regulators::<T>::put(&regulators);
+
+			Self::deposit_event(Event::RegulatorRemoved { account_id });
+
+			let mut quorum = Self::usage_quorum();
+			let threshold = quorum.threshold.min(regulators.len() as u32).max(1);
+			if threshold != quorum.threshold {
+				quorum.threshold = threshold;
+				UsageQuorumConfig::<T>::put(quorum);
+				Self::deposit_event(Event::UsageQuorumChanged {
+					threshold,
+					tolerance: quorum.tolerance,
+				});
+			}
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::add_chain_id())]
+		pub fn add_chain_id(origin: OriginFor<T>, chain_id: Vec<u8>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let bounded_chain_id: BoundedVec<u8, T::ChainIdMaxLength> =
+				chain_id.clone().try_into().map_err(|_| Error::<T>::BadChainId)?;
+
+			let mut chain_ids = ChainIds::<T>::get();
+			ensure!(!chain_ids.contains(&bounded_chain_id), Error::<T>::AlreadyExist);
+
+			chain_ids.insert(bounded_chain_id);
+			ChainIds::<T>::put(&chain_ids);
+
+			Self::deposit_event(Event::ChainIdAdded { chain_id });
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::remove_chain_id())]
+		pub fn remove_chain_id(
+			origin: OriginFor<T>,
+			chain_id: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let bounded_chain_id: BoundedVec<u8, T::ChainIdMaxLength> =
+				chain_id.clone().try_into().map_err(|_| Error::<T>::BadChainId)?;
+
+			let mut chain_ids = ChainIds::<T>::get();
+			ensure!(chain_ids.contains(&bounded_chain_id), Error::<T>::NotExist);
+
+			chain_ids.remove(&bounded_chain_id);
+			ChainIds::<T>::put(&chain_ids);
+			ChainQuotaPrices::<T>::remove(&bounded_chain_id);
+
+			Self::deposit_event(Event::ChainIdRemoved { chain_id });
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_quota_price())]
+		pub fn set_quota_price(
+			origin: OriginFor<T>,
+			#[pallet::compact] price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateQuotaPriceOrigin::ensure_origin(origin)?;
+			ensure!(!price.is_zero(), Error::<T>::InvalidQuotaPrice);
+
+			QuotaPrice::<T>::put(price);
+
+			Self::deposit_event(Event::QuotaPriceChanged { price });
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_chain_quota_price())]
+		pub fn set_chain_quota_price(
+			origin: OriginFor<T>,
+			chain_id: Vec<u8>,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateQuotaPriceOrigin::ensure_origin(origin)?;
+
+			let bounded_chain_id: BoundedVec<u8, T::ChainIdMaxLength> =
+				chain_id.clone().try_into().map_err(|_| Error::<T>::BadChainId)?;
+			ensure!(Self::chain_ids().contains(&bounded_chain_id), Error::<T>::NotExist);
+
+			match price {
+				Some(price) => {
+					ensure!(!price.is_zero(), Error::<T>::InvalidQuotaPrice);
+					ChainQuotaPrices::<T>::insert(&bounded_chain_id, price);
+				},
+				None => ChainQuotaPrices::<T>::remove(&bounded_chain_id),
+			}
+
+			Self::deposit_event(Event::ChainQuotaPriceChanged { chain_id, price });
+
+			Ok(().into())
+		}
+
+		/// Add a member to a project or change its role, requires the `Admin` role.
+		#[pallet::weight(T::WeightInfo::add_project_member())]
+		pub fn add_project_member(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			member: T::AccountId,
+			role: ProjectRole,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(member != project.consumer, Error::<T>::AlreadyExist);
+
+			let mut members = Self::project_members(&project_id).into_inner();
+			match members.iter_mut().find(|(account, _)| account == &member) {
+				Some((_, member_role)) => *member_role = role,
+				None => members.push((member.clone(), role)),
+			}
+			let bounded_members: BoundedVec<_, T::MaxProjectMembers> =
+				members.try_into().map_err(|_| Error::<T>::TooManyProjectMembers)?;
+			ProjectMembers::<T>::insert(&project_id, bounded_members);
+
+			Self::deposit_event(Event::ProjectMemberAdded { project_id, member, role });
+			Ok(().into())
+		}
+
+		/// Remove a member from a project, requires the `Admin` role.
+		///
+		/// Members can also remove themselves.
+		#[pallet::weight(T::WeightInfo::remove_project_member())]
+		pub fn remove_project_member(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			member: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				who == member || Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+
+			let mut members = Self::project_members(&project_id);
+			let index = members
+				.iter()
+				.position(|(account, _)| account == &member)
+				.ok_or(Error::<T>::NotExist)?;
+			members.remove(index);
+			ProjectMembers::<T>::insert(&project_id, members);
+
+			Self::deposit_event(Event::ProjectMemberRemoved { project_id, member });
+			Ok(().into())
+		}
+
+		/// Set the rate limit of a project, requires the `Admin` role.
+		///
+		/// Gateways enforce the stricter of this limit and the cap set by regulators.
+		#[pallet::weight(T::WeightInfo::set_project_rate_limit())]
+		pub fn set_project_rate_limit(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			limit: RateLimit,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+
+			ProjectRateLimits::<T>::insert(&project_id, limit);
+
+			Self::deposit_event(Event::ProjectRateLimitChanged { project_id, limit });
+			Ok(().into())
+		}
+
+		/// Set the rate limit cap of a project, e.g. according to its plan tier.
+		#[pallet::weight(T::WeightInfo::set_project_rate_limit_cap())]
+		pub fn set_project_rate_limit_cap(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			limit: RateLimit,
+		) -> DispatchResultWithPostInfo {
+			let regulator = ensure_signed(origin)?;
+			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
+			ensure!(Projects::<T>::contains_key(&project_id), Error::<T>::NotExist);
+
+			ProjectRateLimitCaps::<T>::insert(&project_id, limit);
+
+			Self::deposit_event(Event::ProjectRateLimitCapChanged { project_id, limit });
+			Ok(().into())
+		}
+
+		/// Add the hash of an API key to a project, requires the `Admin` role.
+		///
+		/// Expired keys of the project are removed.
+		#[pallet::weight(T::WeightInfo::add_api_key())]
+		pub fn add_api_key(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			key_hash: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_api_key_admin(&who, &project_id, expires_at)?;
+
+			let mut keys = Self::unexpired_api_keys(&project_id);
+			ensure!(!keys.iter().any(|key| key.key_hash == key_hash), Error::<T>::AlreadyExist);
+			keys.push(ApiKey { key_hash, expires_at });
+			Self::put_api_keys(&project_id, keys)?;
+
+			Self::deposit_event(Event::ApiKeyAdded { project_id, key_hash, expires_at });
+			Ok(().into())
+		}
+
+		/// Revoke an API key of a project, requires the `Admin` role.
+		#[pallet::weight(T::WeightInfo::revoke_api_key())]
+		pub fn revoke_api_key(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			key_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_api_key_admin(&who, &project_id, None)?;
+
+			let mut keys = Self::project_api_keys(&project_id).into_inner();
+			let index = keys
+				.iter()
+				.position(|key| key.key_hash == key_hash)
+				.ok_or(Error::<T>::NotExist)?;
+			keys.remove(index);
+			Self::put_api_keys(&project_id, keys)?;
+
+			Self::deposit_event(Event::ApiKeyRevoked { project_id, key_hash });
+			Ok(().into())
+		}
+
+		/// Replace an API key of a project by a new one, requires the `Admin` role.
+		#[pallet::weight(T::WeightInfo::rotate_api_key())]
+		pub fn rotate_api_key(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			old_key_hash: T::Hash,
+			new_key_hash: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_api_key_admin(&who, &project_id, expires_at)?;
+
+			let mut keys = Self::project_api_keys(&project_id).into_inner();
+			ensure!(!keys.iter().any(|key| key.key_hash == new_key_hash), Error::<T>::AlreadyExist);
+			let key = keys
+				.iter_mut()
+				.find(|key| key.key_hash == old_key_hash)
+				.ok_or(Error::<T>::NotExist)?;
+			*key = ApiKey { key_hash: new_key_hash, expires_at };
+			Self::put_api_keys(&project_id, keys)?;
+
+			Self::deposit_event(Event::ApiKeyRotated {
+				project_id,
+				old_key_hash,
+				new_key_hash,
+				expires_at,
+			});
+			Ok(().into())
+		}
+
+		/// Set the metadata of a project, requires the `Admin` role.
+		///
+		/// A deposit proportional to the size of the metadata is reserved from the caller, the
+		/// deposit of the previous metadata is returned.
+		#[pallet::weight(T::WeightInfo::set_project_metadata())]
+		#[transactional]
+		pub fn set_project_metadata(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			name: Vec<u8>,
+			description: Vec<u8>,
+			allowed_origins: Vec<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+
+			let bytes = name.len() +
+				description.len() +
+				allowed_origins.iter().map(|origin| origin.len()).sum::<usize>();
+			let metadata = ProjectMetadata {
+				name: name.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+				description: description.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+				allowed_origins: allowed_origins
+					.into_iter()
+					.map(|origin| origin.try_into())
+					.collect::<Result<Vec<MetadataText<T>>, _>>()
+					.map_err(|_| Error::<T>::MetadataTooLong)?
+					.try_into()
+					.map_err(|_| Error::<T>::TooManyAllowedOrigins)?,
+			};
+
+			let deposit = T::MetadataDepositBase::get().saturating_add(
+				T::MetadataDepositPerByte::get().saturating_mul(bytes.saturated_into()),
+			);
+			Self::release_metadata_deposit(&project_id);
+			T::Currency::reserve(&who, deposit)?;
+
+			ProjectMetadataStore::<T>::insert(&project_id, metadata);
+			ProjectMetadataDeposits::<T>::insert(&project_id, (who, deposit));
+
+			Self::deposit_event(Event::ProjectMetadataSet { project_id, deposit });
+			Ok(().into())
+		}
+
+		/// Clear the metadata of a project and return its deposit, requires the `Admin` role.
+		#[pallet::weight(T::WeightInfo::clear_project_metadata())]
+		pub fn clear_project_metadata(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
 		) -> DispatchResultWithPostInfo {
-			let regulator = ensure_signed(origin)?;
-			ensure!(Self::regulators().contains(&regulator), Error::<T>::PermissionDenied);
+			let who = ensure_signed(origin)?;
 
-			let mut provider = Self::providers(&provider_id).ok_or(Error::<T>::NotExist)?;
-			ensure!(provider.state == ProviderState::Active, Error::<T>::InvalidProviderState);
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(ProjectMetadataStore::<T>::contains_key(&project_id), Error::<T>::NotExist);
 
-			T::DapiStaking::unregister(provider_id.clone())?;
+			ProjectMetadataStore::<T>::remove(&project_id);
+			Self::release_metadata_deposit(&project_id);
 
-			provider.state = ProviderState::InActive;
-			Providers::<T>::insert(&provider_id, provider.clone());
+			Self::deposit_event(Event::ProjectMetadataCleared { project_id });
+			Ok(().into())
+		}
 
-			Self::deposit_event(Event::<T>::ProviderDeactivated {
-				provider_id,
-				provider_type: provider.provider_type,
-				reason,
-			});
+		/// Set the fractions of the quota at which `ProjectQuotaThresholdReached` is emitted,
+		/// requires the `Admin` role.
+		#[pallet::weight(T::WeightInfo::set_quota_thresholds())]
+		pub fn set_quota_thresholds(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			mut thresholds: Vec<Percent>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				Self::has_role(&project_id, &project, &who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
+			ensure!(
+				thresholds.iter().all(|threshold| !threshold.is_zero()),
+				Error::<T>::InvalidQuotaThreshold
+			);
+
+			thresholds.sort_unstable();
+			thresholds.dedup();
+			let bounded_thresholds: BoundedVec<Percent, T::MaxQuotaThresholds> =
+				thresholds.clone().try_into().map_err(|_| Error::<T>::TooManyQuotaThresholds)?;
+			QuotaThresholds::<T>::insert(&project_id, bounded_thresholds);
+
+			Self::deposit_event(Event::QuotaThresholdsChanged { project_id, thresholds });
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::add_regulator())]
-		pub fn add_regulator(
+		/// Pre-authorize deposits of `amount` made automatically whenever the project reaches
+		/// one of its quota thresholds or its quota, up to `max_spend` in total.
+		///
+		/// Top-ups are paid by the consumer, so only the consumer can set them. A zero `amount`
+		/// disables the auto top-up.
+		#[pallet::weight(T::WeightInfo::set_auto_top_up())]
+		pub fn set_auto_top_up(
 			origin: OriginFor<T>,
-			account_id: T::AccountId,
+			project_id: T::MassbitId,
+			#[pallet::compact] amount: BalanceOf<T>,
+			#[pallet::compact] max_spend: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
-			let _ = ensure_root(origin);
+			let consumer = ensure_signed(origin)?;
 
-			let mut regulators = Regulators::<T>::get();
-			ensure!(!regulators.contains(&account_id), Error::<T>::AlreadyExist);
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(project.consumer == consumer, Error::<T>::NotOwner);
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
 
-			regulators.insert(account_id.clone());
-			Regulators::<T>::put(&regulators);
+			if amount.is_zero() {
+				AutoTopUps::<T>::remove(&project_id);
+			} else {
+				AutoTopUps::<T>::insert(
+					&project_id,
+					AutoTopUp { amount, max_spend, spent: Zero::zero() },
+				);
+			}
 
-			Self::deposit_event(Event::RegulatorAdded { account_id });
+			Self::deposit_event(Event::AutoTopUpChanged { project_id, amount, max_spend });
+			Ok(().into())
+		}
+
+		/// Dispute usage of a project that is still in its challenge period.
+		///
+		/// Only the consumer of the project can dispute, reserving `UsageDisputeBond` until the
+		/// dispute is resolved or `UsageDisputePeriod` is over.
+		#[pallet::weight(T::WeightInfo::dispute_project_usage())]
+		pub fn dispute_project_usage(
+			origin: OriginFor<T>,
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let consumer = ensure_signed(origin)?;
+
+			let project = Projects::<T>::get(&project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(project.consumer == consumer, Error::<T>::NotOwner);
+
+			let key = (Self::bounded_chain_id(chain_id.clone())?, period);
+			let mut pending =
+				PendingUsages::<T>::get(&project_id, &key).ok_or(Error::<T>::NotExist)?;
+			ensure!(pending.dispute.is_none(), Error::<T>::AlreadyDisputed);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < pending.challenge_end, Error::<T>::ChallengePeriodOver);
+
+			let bond = T::UsageDisputeBond::get();
+			T::Currency::reserve(&consumer, bond)?;
+
+			let deadline = now.saturating_add(T::UsageDisputePeriod::get());
+			pending.dispute = Some(UsageDispute {
+				consumer: consumer.clone(),
+				bond,
+				votes: Vec::new(),
+				deadline,
+			});
+			PendingUsages::<T>::insert(&project_id, &key, pending);
+			UsageChallengeEnds::<T>::append(deadline, (project_id.clone(), key.0, period));
 
+			Self::deposit_event(Event::UsageDisputed { project_id, chain_id, period, consumer });
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::remove_regulator())]
-		pub fn remove_regulator(
+		/// Resolve a usage dispute.
+		///
+		/// Root resolves the dispute immediately. Otherwise regulators that did not report the
+		/// usage vote, and the dispute is resolved once the quorum threshold, capped by the number
+		/// of such regulators, votes for the same outcome. The losing side forfeits its bonds, and
+		/// the usage is rolled back if the consumer wins.
+		///
+		/// A dispute that is not resolved by its deadline is settled in `on_initialize`.
+		#[pallet::weight(T::WeightInfo::resolve_usage_dispute())]
+		#[transactional]
+		pub fn resolve_usage_dispute(
 			origin: OriginFor<T>,
-			account_id: T::AccountId,
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			period: UsagePeriod<T::BlockNumber>,
+			consumer_wins: bool,
 		) -> DispatchResultWithPostInfo {
-			let _ = ensure_root(origin);
+			let key = (Self::bounded_chain_id(chain_id.clone())?, period);
+			let mut pending =
+				PendingUsages::<T>::get(&project_id, &key).ok_or(Error::<T>::NotExist)?;
+			let mut dispute = pending.dispute.take().ok_or(Error::<T>::NotDisputed)?;
+
+			if ensure_root(origin.clone()).is_err() {
+				let regulator = ensure_signed(origin)?;
+				ensure!(
+					Self::regulators().contains(&regulator) &&
+						!pending.reporters.iter().any(|(reporter, _)| reporter == &regulator),
+					Error::<T>::PermissionDenied
+				);
+				ensure!(
+					!dispute.votes.iter().any(|(voter, _)| voter == &regulator),
+					Error::<T>::DuplicateDisputeVote
+				);
+				dispute.votes.push((regulator.clone(), consumer_wins));
+
+				Self::deposit_event(Event::UsageDisputeVoted {
+					project_id: project_id.clone(),
+					chain_id: chain_id.clone(),
+					period,
+					regulator,
+					consumer_wins,
+				});
+
+				let voters = Self::regulators()
+					.iter()
+					.filter(|regulator| {
+						!pending.reporters.iter().any(|(reporter, _)| reporter == *regulator)
+					})
+					.count() as u32;
+				let threshold = Self::usage_quorum().threshold.min(voters).max(1);
+				let votes = dispute.votes.iter().filter(|(_, vote)| *vote == consumer_wins).count();
+				if (votes as u32) < threshold {
+					pending.dispute = Some(dispute);
+					PendingUsages::<T>::insert(&project_id, &key, pending);
+					return Ok(().into())
+				}
+			}
+
+			PendingUsages::<T>::remove(&project_id, &key);
+			if consumer_wins {
+				T::Currency::unreserve(&dispute.consumer, dispute.bond);
+				for (reporter, bond) in pending.reporters {
+					T::OnDisputeSlash::on_unbalanced(
+						T::Currency::slash_reserved(&reporter, bond).0,
+					);
+				}
+			} else {
+				T::OnDisputeSlash::on_unbalanced(
+					T::Currency::slash_reserved(&dispute.consumer, dispute.bond).0,
+				);
+				Self::unreserve_report_bonds(pending.reporters.iter().map(|(r, bond)| (r, *bond)));
+				Self::apply_project_usage(&project_id, &key.0, pending.usage)?;
+			}
+
+			Self::deposit_event(Event::UsageDisputeResolved {
+				project_id,
+				chain_id,
+				period,
+				consumer_wins,
+			});
+			Ok(().into())
+		}
 
-			let mut regulators = Regulators::<T>::get();
-			ensure!(regulators.contains(&account_id), Error::<T>::NotExist);
+		/// Set how many regulators must report matching usage before it is accepted.
+		///
+		/// Reports match when they differ by at most `tolerance` of the larger one.
+		#[pallet::weight(T::WeightInfo::set_usage_quorum())]
+		pub fn set_usage_quorum(
+			origin: OriginFor<T>,
+			threshold: u32,
+			tolerance: Perbill,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateRegulatorOrigin::ensure_origin(origin)?;
+			ensure!(
+				threshold > 0 && threshold as usize <= Self::regulators().len(),
+				Error::<T>::InvalidUsageQuorum
+			);
 
-			regulators.remove(&account_id);
-			Regulators::<T>::put(&regulators);
+			UsageQuorumConfig::<T>::put(UsageQuorum { threshold, tolerance });
 
-			Self::deposit_event(Event::RegulatorRemoved { account_id });
+			Self::deposit_event(Event::UsageQuorumChanged { threshold, tolerance });
 
 			Ok(().into())
 		}
+	}
 
-		#[pallet::weight(T::WeightInfo::add_chain_id())]
-		pub fn add_chain_id(origin: OriginFor<T>, chain_id: Vec<u8>) -> DispatchResultWithPostInfo {
-			let _ = ensure_root(origin);
+	impl<T: Config> Pallet<T> {
+		fn do_register_project(
+			consumer: T::AccountId,
+			project_id: T::MassbitId,
+			chain_id: Vec<u8>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(!<Projects<T>>::contains_key(&project_id), Error::<T>::AlreadyExist);
 
 			let bounded_chain_id: BoundedVec<u8, T::ChainIdMaxLength> =
 				chain_id.clone().try_into().map_err(|_| Error::<T>::BadChainId)?;
+			ensure!(Self::chain_ids().contains(&bounded_chain_id), Error::<T>::NotExist);
 
-			let mut chain_ids = ChainIds::<T>::get();
-			ensure!(!chain_ids.contains(&bounded_chain_id), Error::<T>::AlreadyExist);
+			let escrow = Self::take_deposit(&consumer, deposit)?;
 
-			chain_ids.insert(bounded_chain_id);
-			ChainIds::<T>::put(&chain_ids);
+			let quota_price = Self::quota_price_of(&bounded_chain_id);
+			let quota = Self::calculate_quota(deposit, quota_price);
+			let project = Project {
+				consumer: consumer.clone(),
+				chains: vec![ChainQuota {
+					chain_id: bounded_chain_id,
+					quota,
+					usage: 0,
+					quota_price,
+					escrow,
+					paid: deposit.saturating_sub(escrow),
+				}],
+				state: ProjectState::Active,
+			};
 
-			Self::deposit_event(Event::ChainIdAdded { chain_id });
+			<Projects<T>>::insert(&project_id, project);
+			ProjectsByConsumer::<T>::insert(&consumer, &project_id, ());
 
-			Ok(().into())
+			Self::deposit_event(Event::ProjectRegistered { project_id, consumer, chain_id, quota });
+			Ok(())
 		}
 
-		#[pallet::weight(T::WeightInfo::remove_chain_id())]
-		pub fn remove_chain_id(
-			origin: OriginFor<T>,
+		/// Puts a jailed provider back in service once its jail time is over.
+		fn release_jailed_provider(provider_id: &T::MassbitId, now: T::BlockNumber) -> Weight {
+			let mut provider = match Self::providers(provider_id) {
+				Some(provider) if provider.state == ProviderState::Jailed { until_block: now } =>
+					provider,
+				_ => return T::DbWeight::get().reads(1),
+			};
+
+			// Only fails if dAPI staking no longer knows the provider as jailed.
+			let _ = T::DapiStaking::unjail(provider_id.clone());
+
+			provider.state = ProviderState::Active;
+			Providers::<T>::insert(provider_id, provider.clone());
+			ActiveProvidersByChain::<T>::insert(&provider.chain_id, provider_id, ());
+
+			Self::deposit_event(Event::<T>::ProviderUnjailed { provider_id: provider_id.clone() });
+			T::DbWeight::get().reads_writes(2, 4)
+		}
+
+		fn do_register_provider(
+			provider_id: T::MassbitId,
+			provider_type: ProviderType,
+			operator: T::AccountId,
 			chain_id: Vec<u8>,
-		) -> DispatchResultWithPostInfo {
-			let _ = ensure_root(origin);
+			state: ProviderState<T::BlockNumber>,
+		) -> DispatchResult {
+			ensure!(!<Providers<T>>::contains_key(&provider_id), Error::<T>::AlreadyExist);
 
 			let bounded_chain_id: BoundedVec<u8, T::ChainIdMaxLength> =
 				chain_id.clone().try_into().map_err(|_| Error::<T>::BadChainId)?;
+			ensure!(Self::chain_ids().contains(&bounded_chain_id), Error::<T>::NotExist);
 
-			let mut chain_ids = ChainIds::<T>::get();
-			ensure!(chain_ids.contains(&bounded_chain_id), Error::<T>::NotExist);
+			ProvidersByOperator::<T>::insert(&operator, &provider_id, ());
+			if state == ProviderState::Active {
+				ActiveProvidersByChain::<T>::insert(&bounded_chain_id, &provider_id, ());
+			}
+			<Providers<T>>::insert(
+				&provider_id,
+				Provider {
+					provider_type,
+					operator: operator.clone(),
+					chain_id: bounded_chain_id,
+					state,
+				},
+			);
 
-			chain_ids.remove(&bounded_chain_id);
-			ChainIds::<T>::put(&chain_ids);
+			Self::deposit_event(Event::ProviderRegistered {
+				provider_id,
+				provider_type,
+				operator,
+				chain_id,
+			});
+			Ok(())
+		}
 
-			Self::deposit_event(Event::ChainIdRemoved { chain_id });
+		/// Derive a new id from `who`, a global nonce and the parent block hash.
+		///
+		/// The id is formatted as a UUID string so it keeps the 36-byte layout of ids generated
+		/// off chain.
+		fn generate_massbit_id(who: &T::AccountId) -> Result<T::MassbitId, DispatchError> {
+			let nonce = MassbitIdNonce::<T>::mutate(|nonce| {
+				let current = *nonce;
+				*nonce = nonce.wrapping_add(1);
+				current
+			});
+			let parent_hash = frame_system::Pallet::<T>::parent_hash();
+			let mut bytes = blake2_256(&(who, nonce, parent_hash).encode());
+			// Mark the id as a random (version 4) UUID.
+			bytes[6] = (bytes[6] & 0x0f) | 0x40;
+			bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+			const HEX: &[u8; 16] = b"0123456789abcdef";
+			let mut id = Vec::with_capacity(36);
+			for (i, byte) in bytes[..16].iter().enumerate() {
+				if matches!(i, 4 | 6 | 8 | 10) {
+					id.push(b'-');
+				}
+				id.push(HEX[(byte >> 4) as usize]);
+				id.push(HEX[(byte & 0x0f) as usize]);
+			}
+
+			// Ids that don't use all 36 bytes would collide, so reject them too.
+			let mut input = &id[..];
+			let massbit_id =
+				T::MassbitId::decode(&mut input).map_err(|_| Error::<T>::BadMassbitId)?;
+			ensure!(input.is_empty(), Error::<T>::BadMassbitId);
+			Ok(massbit_id)
+		}
+
+		fn bounded_chain_id(chain_id: Vec<u8>) -> Result<ChainId<T>, DispatchError> {
+			Ok(chain_id.try_into().map_err(|_| Error::<T>::BadChainId)?)
+		}
+
+		/// Price of a single request on the given chain, falling back to `QuotaPrice`.
+		fn quota_price_of(chain_id: &ChainId<T>) -> BalanceOf<T> {
+			Self::chain_quota_price(chain_id).unwrap_or_else(Self::quota_price)
+		}
+
+		/// Metadata of a project.
+		pub fn project_metadata(
+			project_id: &T::MassbitId,
+		) -> Option<ProjectMetadata<Vec<u8>, Vec<Vec<u8>>>> {
+			ProjectMetadataStore::<T>::get(project_id).map(|metadata| ProjectMetadata {
+				name: metadata.name.into_inner(),
+				description: metadata.description.into_inner(),
+				allowed_origins: metadata
+					.allowed_origins
+					.into_iter()
+					.map(|origin| origin.into_inner())
+					.collect(),
+			})
+		}
 
+		/// Metadata of a provider.
+		pub fn provider_metadata(provider_id: &T::MassbitId) -> Option<ProviderMetadata<Vec<u8>>> {
+			ProviderMetadataStore::<T>::get(provider_id).map(|metadata| ProviderMetadata {
+				region: metadata.region.into_inner(),
+				endpoint: metadata.endpoint.into_inner(),
+				client: metadata.client.into_inner(),
+				capabilities: metadata.capabilities,
+			})
+		}
+
+		/// Page of the projects owned by `consumer`, skipping the first `start` ones.
+		pub fn projects_of(consumer: &T::AccountId, start: u32, limit: u32) -> Vec<T::MassbitId> {
+			ProjectsByConsumer::<T>::iter_key_prefix(consumer)
+				.skip(start as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Page of the providers run by `operator`, skipping the first `start` ones.
+		pub fn providers_of(operator: &T::AccountId, start: u32, limit: u32) -> Vec<T::MassbitId> {
+			ProvidersByOperator::<T>::iter_key_prefix(operator)
+				.skip(start as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Page of the active providers serving `chain_id`, skipping the first `start` ones.
+		pub fn active_providers_of(chain_id: Vec<u8>, start: u32, limit: u32) -> Vec<T::MassbitId> {
+			let chain_id: ChainId<T> = match chain_id.try_into() {
+				Ok(chain_id) => chain_id,
+				Err(_) => return Vec::new(),
+			};
+			ActiveProvidersByChain::<T>::iter_key_prefix(chain_id)
+				.skip(start as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Page of the active providers serving `chain_id` that support every capability in
+		/// `capabilities`, skipping the first `start` matching ones.
+		pub fn active_providers_with(
+			chain_id: Vec<u8>,
+			capabilities: ProviderCapabilities,
+			start: u32,
+			limit: u32,
+		) -> Vec<T::MassbitId> {
+			let chain_id: ChainId<T> = match chain_id.try_into() {
+				Ok(chain_id) => chain_id,
+				Err(_) => return Vec::new(),
+			};
+			ActiveProvidersByChain::<T>::iter_key_prefix(chain_id)
+				.filter(|provider_id| {
+					ProviderMetadataStore::<T>::get(provider_id)
+						.map(|metadata| metadata.capabilities)
+						.unwrap_or_default()
+						.contains(&capabilities)
+				})
+				.skip(start as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Rate limit gateways enforce for a project, the stricter of the limit set by its admins
+		/// and the cap set by regulators.
+		pub fn project_rate_limit(project_id: &T::MassbitId) -> Option<RateLimit> {
+			if !Projects::<T>::contains_key(project_id) {
+				return None
+			}
+			Some(
+				Self::project_rate_limit_of(project_id)
+					.stricter(Self::project_rate_limit_cap(project_id)),
+			)
+		}
+
+		/// `true` if the API key is registered for the project, not expired, and the project is
+		/// active.
+		pub fn is_valid_api_key(project_id: &T::MassbitId, key_hash: &T::Hash) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			Projects::<T>::get(project_id)
+				.map_or(false, |project| project.state == ProjectState::Active) &&
+				Self::project_api_keys(project_id).iter().any(|key| {
+					&key.key_hash == key_hash && key.expires_at.map_or(true, |end| now < end)
+				})
+		}
+
+		/// Ensure `who` can manage the API keys of the project and `expires_at` is in the future.
+		fn ensure_api_key_admin(
+			who: &T::AccountId,
+			project_id: &T::MassbitId,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let project = Projects::<T>::get(project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				Self::has_role(project_id, &project, who, ProjectRole::Admin),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
+			ensure!(
+				expires_at.map_or(true, |end| end > frame_system::Pallet::<T>::block_number()),
+				Error::<T>::InvalidApiKeyExpiry
+			);
+			Ok(())
+		}
+
+		/// API keys of the project that are not expired.
+		fn unexpired_api_keys(project_id: &T::MassbitId) -> Vec<ApiKey<T::Hash, T::BlockNumber>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut keys = Self::project_api_keys(project_id).into_inner();
+			keys.retain(|key| key.expires_at.map_or(true, |end| now < end));
+			keys
+		}
+
+		fn put_api_keys(
+			project_id: &T::MassbitId,
+			keys: Vec<ApiKey<T::Hash, T::BlockNumber>>,
+		) -> DispatchResult {
+			let bounded_keys: BoundedVec<_, T::MaxApiKeys> =
+				keys.try_into().map_err(|_| Error::<T>::TooManyApiKeys)?;
+			ProjectApiKeys::<T>::insert(project_id, bounded_keys);
+			Ok(())
+		}
+
+		/// Return the metadata deposit of a project to the account that reserved it.
+		fn release_metadata_deposit(project_id: &T::MassbitId) {
+			if let Some((depositor, deposit)) = ProjectMetadataDeposits::<T>::take(project_id) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+		}
+
+		/// Return the metadata deposit of a provider to the account that reserved it.
+		fn release_provider_metadata_deposit(provider_id: &T::MassbitId) {
+			if let Some((depositor, deposit)) = ProviderMetadataDeposits::<T>::take(provider_id) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+		}
+
+		/// `true` if `who` holds at least `role` in the project.
+		pub fn has_project_role(
+			project_id: &T::MassbitId,
+			who: &T::AccountId,
+			role: ProjectRole,
+		) -> bool {
+			Projects::<T>::get(project_id)
+				.map_or(false, |project| Self::has_role(project_id, &project, who, role))
+		}
+
+		/// `true` if `who` holds at least `role` in the project, the consumer holds every role.
+		fn has_role(
+			project_id: &T::MassbitId,
+			project: &Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+			who: &T::AccountId,
+			role: ProjectRole,
+		) -> bool {
+			&project.consumer == who ||
+				Self::project_members(project_id)
+					.iter()
+					.any(|(member, member_role)| member == who && *member_role >= role)
+		}
+
+		/// Ensure `who` is the consumer of the project or a regulator.
+		fn ensure_consumer_or_regulator(
+			who: &T::AccountId,
+			project: &Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+		) -> DispatchResult {
+			ensure!(
+				&project.consumer == who || Self::regulators().contains(who),
+				Error::<T>::PermissionDenied
+			);
+			Ok(())
+		}
+
+		/// Refund the unused quota of a project and clean up its usage tracking.
+		///
+		/// The project must have no usage left in its challenge period. The caller stores the
+		/// closed project.
+		fn do_close_project(
+			project_id: &T::MassbitId,
+			project: &mut Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+		) -> DispatchResult {
+			ensure!(
+				PendingUsages::<T>::iter_key_prefix(project_id).next().is_none(),
+				Error::<T>::UsageUnderChallenge
+			);
+
+			let refund = Self::refund_project(project_id, project)?;
+
+			for chain in project.chains.iter_mut() {
+				chain.quota = chain.usage;
+			}
+			project.state = ProjectState::Closed;
+			let _ = LastUsagePeriod::<T>::remove_prefix(project_id, None);
+			QuotaThresholds::<T>::remove(project_id);
+			AutoTopUps::<T>::remove(project_id);
+			PendingProjectTransfers::<T>::remove(project_id);
+			for (_, reports) in PendingUsageReports::<T>::drain_prefix(project_id) {
+				Self::unreserve_report_bonds(reports.iter().map(|r| (&r.regulator, r.bond)));
+			}
+
+			Self::deposit_event(Event::ProjectClosed { project_id: project_id.clone(), refund });
+			Ok(())
+		}
+
+		/// Record the usage of a project on a chain reported by a regulator for `period`.
+		///
+		/// Periods of a project on a chain must not overlap so that a retried report is never
+		/// counted twice. Each report reserves `UsageReportBond` from the regulator. The report is
+		/// accepted once the quorum of regulators agrees on it, using the lower median of the
+		/// matching reports.
+		fn do_submit_project_usage(
+			regulator: &T::AccountId,
+			project_id: &T::MassbitId,
+			chain_id: &ChainId<T>,
+			usage: u128,
+			period: UsagePeriod<T::BlockNumber>,
+		) -> DispatchResult {
+			let project = Projects::<T>::get(project_id).ok_or(Error::<T>::NotExist)?;
+			ensure!(project.state != ProjectState::Closed, Error::<T>::InvalidProjectState);
+			ensure!(project.chain(chain_id).is_some(), Error::<T>::NotExist);
+
+			ensure!(
+				period.start <= period.end &&
+					period.end <= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidUsagePeriod
+			);
+			if let Some(last_period) = Self::last_usage_period(project_id, chain_id) {
+				ensure!(period.start > last_period.end, Error::<T>::OverlappingUsagePeriod);
+			}
+
+			let key = (chain_id.clone(), period);
+			let mut reports = Self::pending_usage_reports(project_id, &key);
+			ensure!(
+				!reports.iter().any(|report| &report.regulator == regulator),
+				Error::<T>::DuplicateUsageReport
+			);
+
+			let bond = T::UsageReportBond::get();
+			T::Currency::reserve(regulator, bond)?;
+			reports.push(UsageReport { regulator: regulator.clone(), usage, bond });
+
+			Self::deposit_event(Event::UsageReportSubmitted {
+				project_id: project_id.clone(),
+				chain_id: chain_id.to_vec(),
+				regulator: regulator.clone(),
+				period,
+				usage,
+			});
+
+			let quorum = Self::usage_quorum();
+			let (matching, others): (Vec<_>, Vec<_>) = reports
+				.into_iter()
+				.partition(|report| Self::is_matching_usage(report.usage, usage, quorum.tolerance));
+			if (matching.len() as u32) < quorum.threshold {
+				let reports = matching.into_iter().chain(others).collect::<Vec<_>>();
+				PendingUsageReports::<T>::insert(project_id, &key, reports);
+				return Ok(())
+			}
+
+			// Regulators that disagree with the quorum get their bonds back.
+			PendingUsageReports::<T>::remove(project_id, &key);
+			Self::unreserve_report_bonds(others.iter().map(|r| (&r.regulator, r.bond)));
+
+			let mut usages = matching.iter().map(|report| report.usage).collect::<Vec<u128>>();
+			usages.sort_unstable();
+			let usage = usages[(usages.len() - 1) / 2];
+			let reporters = matching.into_iter().map(|report| (report.regulator, report.bond));
+
+			Self::finalize_usage_report(project_id, chain_id, usage, period, reporters.collect())
+		}
+
+		/// Accept the usage of a project on a chain for `period`.
+		///
+		/// The usage is applied at the end of the challenge period unless it is disputed.
+		fn finalize_usage_report(
+			project_id: &T::MassbitId,
+			chain_id: &ChainId<T>,
+			usage: u128,
+			period: UsagePeriod<T::BlockNumber>,
+			reporters: Vec<(T::AccountId, BalanceOf<T>)>,
+		) -> DispatchResult {
+			// Pending reports overlapping the accepted period can never be accepted anymore.
+			let stale_keys = PendingUsageReports::<T>::iter_key_prefix(project_id)
+				.filter(|(chain, pending)| chain == chain_id && pending.start <= period.end)
+				.collect::<Vec<_>>();
+			for stale_key in stale_keys {
+				let reports = PendingUsageReports::<T>::take(project_id, stale_key);
+				Self::unreserve_report_bonds(reports.iter().map(|r| (&r.regulator, r.bond)));
+			}
+			LastUsagePeriod::<T>::insert(project_id, chain_id, period);
+
+			Self::deposit_event(Event::UsageReportFinalized {
+				project_id: project_id.clone(),
+				chain_id: chain_id.to_vec(),
+				period,
+				usage,
+			});
+
+			let challenge_period = T::UsageChallengePeriod::get();
+			if challenge_period.is_zero() {
+				Self::unreserve_report_bonds(reporters.iter().map(|(r, bond)| (r, *bond)));
+				return Self::apply_project_usage(project_id, chain_id, usage)
+			}
+
+			let challenge_end =
+				frame_system::Pallet::<T>::block_number().saturating_add(challenge_period);
+			PendingUsages::<T>::insert(
+				project_id,
+				(chain_id, period),
+				PendingUsage { usage, reporters, challenge_end, dispute: None },
+			);
+			UsageChallengeEnds::<T>::append(
+				challenge_end,
+				(project_id.clone(), chain_id.clone(), period),
+			);
+
+			Ok(())
+		}
+
+		/// Apply pending usage whose challenge period is over. Disputed usage is left to
+		/// `resolve_usage_dispute` until the deadline of the dispute, after which the usage is
+		/// applied and both sides get their bonds back.
+		///
+		/// If the usage can't be applied it stays pending and is retried once another challenge
+		/// period is over.
+		fn apply_pending_usage(
+			project_id: &T::MassbitId,
+			chain_id: &ChainId<T>,
+			period: UsagePeriod<T::BlockNumber>,
+			now: T::BlockNumber,
+		) -> Weight {
+			let key = (chain_id, period);
+			let pending = match PendingUsages::<T>::get(project_id, key) {
+				Some(pending) => pending,
+				None => return T::DbWeight::get().reads(1),
+			};
+			let expired = match &pending.dispute {
+				Some(dispute) if dispute.deadline <= now => true,
+				Some(_) => return T::DbWeight::get().reads(1),
+				None => false,
+			};
+
+			let result = with_transaction(|| {
+				PendingUsages::<T>::remove(project_id, key);
+				if let Some(dispute) = &pending.dispute {
+					T::Currency::unreserve(&dispute.consumer, dispute.bond);
+				}
+				Self::unreserve_report_bonds(pending.reporters.iter().map(|(r, bond)| (r, *bond)));
+				match Self::apply_project_usage(project_id, chain_id, pending.usage) {
+					Ok(()) => TransactionOutcome::Commit(Ok(())),
+					Err(error) => TransactionOutcome::Rollback(Err(error)),
+				}
+			});
+			match result {
+				Ok(()) if expired => Self::deposit_event(Event::UsageDisputeExpired {
+					project_id: project_id.clone(),
+					chain_id: chain_id.to_vec(),
+					period,
+				}),
+				Ok(()) => (),
+				Err(error) => {
+					let retry_at =
+						now.saturating_add(T::UsageChallengePeriod::get().max(One::one()));
+					PendingUsages::<T>::mutate(project_id, key, |pending| {
+						if let Some(pending) = pending {
+							pending.challenge_end = retry_at;
+							if let Some(dispute) = &mut pending.dispute {
+								dispute.deadline = retry_at;
+							}
+						}
+					});
+					UsageChallengeEnds::<T>::append(
+						retry_at,
+						(project_id.clone(), chain_id.clone(), period),
+					);
+					Self::deposit_event(Event::UsageApplyFailed {
+						project_id: project_id.clone(),
+						chain_id: chain_id.to_vec(),
+						period,
+						error,
+					});
+				},
+			}
+
+			let reporters = pending.reporters.len() as Weight;
+			T::WeightInfo::apply_project_usage()
+				.saturating_add(T::DbWeight::get().reads_writes(1 + reporters, 1 + reporters))
+		}
+
+		/// Weight of applying `n` accepted usage reports right away, which only happens when
+		/// there is no challenge period.
+		fn immediate_usage_weight(n: u32) -> Weight {
+			if T::UsageChallengePeriod::get().is_zero() {
+				T::WeightInfo::apply_project_usage().saturating_mul(n as Weight)
+			} else {
+				0
+			}
+		}
+
+		/// Add usage to a chain of a project, capped by the quota of the chain.
+		pub(crate) fn apply_project_usage(
+			project_id: &T::MassbitId,
+			chain_id: &ChainId<T>,
+			usage: u128,
+		) -> DispatchResult {
+			let mut project = Projects::<T>::get(project_id).ok_or(Error::<T>::NotExist)?;
+			let chain = project.chain_mut(chain_id).ok_or(Error::<T>::NotExist)?;
+
+			let usage = usage.min(chain.quota.saturating_sub(chain.usage));
+			Self::settle_usage(chain, usage)?;
+
+			let previous_usage = chain.usage;
+			chain.usage = chain.usage.saturating_add(usage);
+			let (quota, current_usage) = (chain.quota, chain.usage);
+
+			let mut top_up = false;
+			for threshold in Self::quota_thresholds(project_id) {
+				let threshold_usage = threshold.mul_ceil(quota);
+				if previous_usage < threshold_usage && threshold_usage <= current_usage {
+					Self::deposit_event(Event::ProjectQuotaThresholdReached {
+						project_id: project_id.clone(),
+						chain_id: chain_id.to_vec(),
+						threshold,
+					});
+					top_up = true;
+				}
+			}
+			if current_usage == quota {
+				Self::deposit_event(Event::ProjectReachedQuota {
+					project_id: project_id.clone(),
+					chain_id: chain_id.to_vec(),
+				});
+				top_up = true;
+			};
+			if top_up && !usage.is_zero() {
+				Self::try_auto_top_up(project_id, &mut project, chain_id);
+			}
+			if project.state == ProjectState::Active && project.is_exhausted() {
+				project.state = ProjectState::Exhausted;
+			}
+
+			Projects::<T>::insert(project_id, project);
+			Self::record_usage_history(project_id, usage);
+
+			Ok(())
+		}
+
+		/// Add usage to the history of the current era.
+		///
+		/// Whenever a new era is started the oldest recorded era is pruned if it is older than
+		/// `UsageHistoryDepth`. Pruning a single era is enough to keep at most
+		/// `UsageHistoryDepth` eras, since the oldest of that many eras is always stale.
+		fn record_usage_history(project_id: &T::MassbitId, usage: u128) {
+			if usage.is_zero() {
+				return
+			}
+
+			let era = T::DapiStaking::current_era();
+			if !ProjectUsageByEra::<T>::contains_key(project_id, era) {
+				let depth = T::UsageHistoryDepth::get();
+				ProjectUsageEras::<T>::mutate(project_id, |eras| {
+					if let Some(&oldest) = eras.first() {
+						if oldest.saturating_add(depth) <= era {
+							eras.remove(0);
+							ProjectUsageByEra::<T>::remove(project_id, oldest);
+						}
+					}
+					let _ = eras.try_push(era);
+				});
+			}
+
+			ProjectUsageByEra::<T>::mutate(project_id, era, |recorded| {
+				*recorded = recorded.saturating_add(usage)
+			});
+		}
+
+		fn unreserve_report_bonds<'a>(
+			bonds: impl Iterator<Item = (&'a T::AccountId, BalanceOf<T>)>,
+		) {
+			for (regulator, bond) in bonds {
+				T::Currency::unreserve(regulator, bond);
+			}
+		}
+
+		/// Deposit to a chain of the project from its consumer as pre-authorized by `AutoTopUps`.
+		fn try_auto_top_up(
+			project_id: &T::MassbitId,
+			project: &mut Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+			chain_id: &ChainId<T>,
+		) {
+			let mut top_up = match Self::auto_top_up(project_id) {
+				Some(top_up) => top_up,
+				None => return,
+			};
+			let spent = top_up.spent.saturating_add(top_up.amount);
+			if spent > top_up.max_spend {
+				return
+			}
+
+			let consumer = project.consumer.clone();
+			match Self::do_deposit_project(&consumer, project, chain_id, top_up.amount) {
+				Ok(quota) => {
+					Self::deposit_event(Event::ProjectAutoToppedUp {
+						project_id: project_id.clone(),
+						chain_id: chain_id.to_vec(),
+						amount: top_up.amount,
+						quota,
+					});
+					top_up.spent = spent;
+					AutoTopUps::<T>::insert(project_id, top_up);
+				},
+				Err(error) => Self::deposit_event(Event::ProjectAutoTopUpFailed {
+					project_id: project_id.clone(),
+					error,
+				}),
+			}
+		}
+
+		/// `true` if two reported usages differ by at most `tolerance` of the larger one.
+		fn is_matching_usage(a: u128, b: u128, tolerance: Perbill) -> bool {
+			let (low, high) = if a < b { (a, b) } else { (b, a) };
+			high - low <= tolerance * high
+		}
+
+		/// Get the account holding escrowed project deposits.
+		pub fn escrow_account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Take a project deposit from `who`. In escrow mode the deposit is moved to the escrow
+		/// account and returned as the escrowed amount, otherwise it is paid out immediately.
+		fn take_deposit(
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			if T::ProjectDepositEscrow::get() {
+				T::Currency::transfer(
+					who,
+					&Self::escrow_account_id(),
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				Ok(amount)
+			} else {
+				let payment = T::Currency::withdraw(
+					who,
+					amount,
+					WithdrawReasons::TRANSFER,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				T::OnProjectPayment::on_unbalanced(payment);
+				Ok(Zero::zero())
+			}
+		}
+
+		/// Deposit to a chain of a project on behalf of `depositor`, checking its role and the state
+		/// of the project.
+		fn deposit_project_to(
+			depositor: T::AccountId,
+			project_id: T::MassbitId,
+			mut project: Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+			chain_id: ChainId<T>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(!deposit.is_zero(), Error::<T>::ZeroDeposit);
+			ensure!(
+				Self::has_role(&project_id, &project, &depositor, ProjectRole::Depositor),
+				Error::<T>::PermissionDenied
+			);
+			ensure!(
+				matches!(project.state, ProjectState::Active | ProjectState::Exhausted),
+				Error::<T>::InvalidProjectState
+			);
+
+			let quota = Self::do_deposit_project(&depositor, &mut project, &chain_id, deposit)?;
+
+			<Projects<T>>::insert(&project_id, project);
+
+			Self::deposit_event(Event::ProjectDeposited {
+				project_id,
+				chain_id: chain_id.to_vec(),
+				quota,
+			});
 			Ok(().into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn calculate_quota(amount: BalanceOf<T>) -> u128 {
-			TryInto::<u128>::try_into(amount)
-				.ok()
-				.unwrap_or_default()
-				.div(1_000_000_000_000_000u128)
+		/// Buy more quota for a chain of a project at the current quota price of the chain.
+		///
+		/// Returns the new quota of the chain.
+		fn do_deposit_project(
+			who: &T::AccountId,
+			project: &mut Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+			chain_id: &ChainId<T>,
+			deposit: BalanceOf<T>,
+		) -> Result<u128, DispatchError> {
+			let chain = project.chain_mut(chain_id).ok_or(Error::<T>::NotExist)?;
+			let escrow = Self::take_deposit(who, deposit)?;
+			chain.escrow = chain.escrow.saturating_add(escrow);
+			chain.paid = chain.paid.saturating_add(deposit.saturating_sub(escrow));
+
+			let quota_price = Self::quota_price_of(chain_id);
+			chain.quota = chain.quota.saturating_add(Self::calculate_quota(deposit, quota_price));
+			chain.quota_price = quota_price;
+			let quota = chain.quota;
+			if project.state == ProjectState::Exhausted && !project.is_exhausted() {
+				project.state = ProjectState::Active;
+			}
+
+			Ok(quota)
+		}
+
+		/// Account for `usage` newly consumed requests of a chain. The matching part of its escrow
+		/// is paid out and the matching part of its paid deposit is no longer refundable.
+		///
+		/// Must be called before `usage` is added to the chain.
+		fn settle_usage(
+			chain: &mut ChainQuota<ChainId<T>, BalanceOf<T>>,
+			usage: u128,
+		) -> DispatchResult {
+			if usage.is_zero() {
+				return Ok(())
+			}
+
+			let remaining = chain.quota.saturating_sub(chain.usage);
+			chain.paid = chain.paid.saturating_sub(Self::usage_share(chain.paid, usage, remaining));
+			if chain.escrow.is_zero() {
+				return Ok(())
+			}
+
+			let amount = Self::usage_share(chain.escrow, usage, remaining);
+			let payment = T::Currency::withdraw(
+				&Self::escrow_account_id(),
+				amount,
+				WithdrawReasons::TRANSFER,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			T::OnProjectPayment::on_unbalanced(payment);
+			chain.escrow = chain.escrow.saturating_sub(amount);
+
+			Ok(())
+		}
+
+		/// Part of `amount`, deposited for `remaining` unused requests, that matches `usage` of
+		/// them.
+		fn usage_share(amount: BalanceOf<T>, usage: u128, remaining: u128) -> BalanceOf<T> {
+			let total: u128 = amount.saturated_into();
+			let share: BalanceOf<T> =
+				multiply_by_rational(total, usage, remaining).unwrap_or(total).saturated_into();
+			share.min(amount)
+		}
+
+		/// Refund the unused quota of every chain of a project to its consumer.
+		///
+		/// What is left in escrow is returned from the escrow account, and what is left of the
+		/// paid deposit from `ProjectRefundAccount` as far as it can afford it. Returns the amount
+		/// refunded.
+		fn refund_project(
+			project_id: &T::MassbitId,
+			project: &mut Project<AccountIdOf<T>, ChainId<T>, BalanceOf<T>>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let mut total: BalanceOf<T> = Zero::zero();
+			for chain in project.chains.iter_mut() {
+				if !chain.escrow.is_zero() {
+					T::Currency::transfer(
+						&Self::escrow_account_id(),
+						&project.consumer,
+						chain.escrow,
+						ExistenceRequirement::KeepAlive,
+					)?;
+				}
+				total = total.saturating_add(chain.escrow);
+				if !chain.paid.is_zero() {
+					let refunded = T::Currency::transfer(
+						&T::ProjectRefundAccount::get(),
+						&project.consumer,
+						chain.paid,
+						ExistenceRequirement::AllowDeath,
+					);
+					if refunded.is_ok() {
+						total = total.saturating_add(chain.paid);
+					} else {
+						Self::deposit_event(Event::ProjectRefundFailed {
+							project_id: project_id.clone(),
+							amount: chain.paid,
+						});
+					}
+				}
+				chain.escrow = Zero::zero();
+				chain.paid = Zero::zero();
+			}
+
+			Ok(total)
+		}
+
+		/// Number of requests that `amount` buys at the given price per request.
+		fn calculate_quota(amount: BalanceOf<T>, price: BalanceOf<T>) -> u128 {
+			let amount: u128 = amount.saturated_into();
+			amount.checked_div(price.saturated_into()).unwrap_or_default()
 		}
 	}
 }
 
+/// Counter for the number of eras that have passed.
+pub type EraIndex = u32;
+
 pub trait DapiStaking<AccountId, Provider, Balance> {
 	fn register(
 		origin: AccountId,
@@ -452,4 +3048,36 @@ pub trait DapiStaking<AccountId, Provider, Balance> {
 	) -> DispatchResultWithPostInfo;
 
 	fn unregister(provider_id: Provider) -> DispatchResultWithPostInfo;
+
+	/// Stop a provider from earning rewards until it is unjailed.
+	fn jail(provider_id: Provider) -> DispatchResultWithPostInfo;
+
+	/// Let a jailed provider earn rewards again.
+	fn unjail(provider_id: Provider) -> DispatchResultWithPostInfo;
+
+	/// Slash the operator and stakers of a provider for the offence described by `reason`.
+	fn slash(provider_id: Provider, reason: ProviderDeactivateReason)
+		-> DispatchResultWithPostInfo;
+
+	/// Hand a provider over to a new operator along with what is left of its deposit and its
+	/// self-stake. Fails while a slash of the provider is deferred.
+	fn change_operator(
+		provider_id: Provider,
+		new_operator: AccountId,
+	) -> DispatchResultWithPostInfo;
+
+	/// Register an unregistered provider again, staking `deposit` from its operator if any.
+	fn reactivate(
+		operator: AccountId,
+		provider_id: Provider,
+		deposit: Balance,
+	) -> DispatchResultWithPostInfo;
+
+	fn current_era() -> EraIndex;
+
+	/// Smallest deposit accepted by `register`.
+	fn minimum_register_deposit() -> Balance;
+
+	/// Upper bound of the weight of `slash`.
+	fn slash_weight() -> Weight;
 }
@@ -0,0 +1,81 @@
+//! Storage migrations of the dapi pallet.
+
+use super::*;
+use codec::Decode;
+use frame_support::{
+	traits::{Get, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Price of a single request before it could be configured.
+	const V0_QUOTA_PRICE: u128 = 1_000_000_000_000_000;
+
+	#[derive(Decode)]
+	struct OldProject<AccountId, ChainId> {
+		consumer: AccountId,
+		chain_id: ChainId,
+		quota: u128,
+		usage: u128,
+	}
+
+	/// Move the quota of every project into a bucket of the chain it was registered with.
+	///
+	/// Deposits used to be paid out in full, so the unused quota is recorded as paid at the price
+	/// it was bought at. It is refunded from `ProjectRefundAccount`, as far as that account can
+	/// afford it, if the project is closed. The escrow account is also endowed so that later
+	/// deposits below the existential deposit can be escrowed.
+	///
+	/// `Providers` keep their encoding, the provider states added since are new variants. The
+	/// indexes of projects by consumer and of providers by operator and chain are filled in.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: Weight = 0;
+		Projects::<T>::translate::<OldProject<T::AccountId, BoundedVec<u8, T::ChainIdMaxLength>>, _>(
+			|project_id, old| {
+				translated += 1;
+				ProjectsByConsumer::<T>::insert(&old.consumer, &project_id, ());
+				let unused = old.quota.saturating_sub(old.usage);
+				let state =
+					if unused.is_zero() { ProjectState::Exhausted } else { ProjectState::Active };
+				Some(Project {
+					consumer: old.consumer,
+					chains: vec![ChainQuota {
+						chain_id: old.chain_id,
+						quota: old.quota,
+						usage: old.usage,
+						quota_price: V0_QUOTA_PRICE.saturated_into(),
+						escrow: Zero::zero(),
+						paid: unused.saturating_mul(V0_QUOTA_PRICE).saturated_into(),
+					}],
+					state,
+				})
+			},
+		);
+
+		let mut providers: Weight = 0;
+		for (provider_id, provider) in Providers::<T>::iter() {
+			providers += 1;
+			ProvidersByOperator::<T>::insert(&provider.operator, &provider_id, ());
+			if provider.state == ProviderState::Active {
+				ActiveProvidersByChain::<T>::insert(&provider.chain_id, &provider_id, ());
+			}
+		}
+
+		let escrow_account = Pallet::<T>::escrow_account_id();
+		if T::Currency::total_balance(&escrow_account).is_zero() {
+			T::Currency::make_free_balance_be(&escrow_account, T::Currency::minimum_balance());
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get()
+			.reads_writes(translated + providers + 2, 2 * translated + 2 * providers + 2)
+	}
+}
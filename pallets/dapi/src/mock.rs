@@ -37,6 +37,9 @@ pub(crate) const MINIMUM_REMAINING_AMOUNT: Balance = 1;
 pub(crate) const MAX_UNLOCKING_CHUNKS: u32 = 4;
 pub(crate) const UNBONDING_PERIOD: EraIndex = 3;
 pub(crate) const MAX_ERA_STAKE_VALUES: u32 = 8;
+pub(crate) const OUT_OF_SYNC_SLASH: u32 = 10;
+pub(crate) const BAD_PERFORMANCE_SLASH: u32 = 5;
+pub(crate) const SLASH_DEFER_DURATION: EraIndex = 2;
 
 // Do note that this needs to at least be 3 for tests to be valid. It can be greater but not
 // smaller.
@@ -134,6 +137,10 @@ parameter_types! {
 	pub const MaxUnlockingChunks: u32 = MAX_UNLOCKING_CHUNKS;
 	pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
 	pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+	pub const OutOfSyncSlash: Perbill = Perbill::from_percent(OUT_OF_SYNC_SLASH);
+	pub const BadPerformanceSlash: Perbill = Perbill::from_percent(BAD_PERFORMANCE_SLASH);
+	pub const SlashStakers: bool = true;
+	pub const SlashDeferDuration: EraIndex = SLASH_DEFER_DURATION;
 }
 
 impl pallet_dapi_staking::Config for TestRuntime {
@@ -150,6 +157,11 @@ impl pallet_dapi_staking::Config for TestRuntime {
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type UnbondingPeriod = UnbondingPeriod;
 	type MaxEraStakeValues = MaxEraStakeValues;
+	type OutOfSyncSlash = OutOfSyncSlash;
+	type BadPerformanceSlash = BadPerformanceSlash;
+	type SlashStakers = SlashStakers;
+	type SlashDeferDuration = SlashDeferDuration;
+	type OnSlash = ();
 	type WeightInfo = pallet_dapi_staking::weights::SubstrateWeight<TestRuntime>;
 }
 
@@ -164,6 +176,29 @@ impl Default for MockProvider {
 
 parameter_types! {
 	pub const ProjectDepositPeriod: BlockNumber = 10;
+	pub const DefaultQuotaPrice: Balance = 1;
+	pub const ProjectRefundAccount: AccountId = 1337;
+	pub const DapiPalletId: PalletId = PalletId(*b"mokdapi_");
+	pub const ProjectDepositEscrow: bool = true;
+	pub const RequireProviderApproval: bool = false;
+	pub const JailDuration: BlockNumber = 10;
+	pub const OffenceWindow: BlockNumber = 100;
+	pub const MaxOffences: u32 = 3;
+	pub const MaxUsageBatchSize: u32 = 100;
+	pub const UsageChallengePeriod: BlockNumber = 10;
+	pub const UsageReportBond: Balance = 10;
+	pub const UsageDisputeBond: Balance = 10;
+	pub const UsageDisputePeriod: BlockNumber = 10;
+	pub const MaxUsageAppliedPerBlock: u32 = 10;
+	pub const UsageHistoryDepth: u32 = 30;
+	pub const MaxQuotaThresholds: u32 = 4;
+	pub const MaxProjectMembers: u32 = 8;
+	pub const MaxProjectChains: u32 = 4;
+	pub const MaxApiKeys: u32 = 8;
+	pub const MaxMetadataLength: u32 = 256;
+	pub const MaxAllowedOrigins: u32 = 8;
+	pub const MetadataDepositBase: Balance = 10;
+	pub const MetadataDepositPerByte: Balance = 1;
 }
 
 impl pallet_dapi::Config for TestRuntime {
@@ -171,9 +206,34 @@ impl pallet_dapi::Config for TestRuntime {
 	type Currency = Balances;
 	type DapiStaking = DapiStaking;
 	type UpdateRegulatorOrigin = EnsureRoot<AccountId>;
+	type UpdateQuotaPriceOrigin = EnsureRoot<AccountId>;
+	type DefaultQuotaPrice = DefaultQuotaPrice;
 	type ChainIdMaxLength = ConstU32<64>;
 	type MassbitId = MockProvider;
 	type OnProjectPayment = ();
+	type ProjectRefundAccount = ProjectRefundAccount;
+	type PalletId = DapiPalletId;
+	type ProjectDepositEscrow = ProjectDepositEscrow;
+	type RequireProviderApproval = RequireProviderApproval;
+	type JailDuration = JailDuration;
+	type OffenceWindow = OffenceWindow;
+	type MaxOffences = MaxOffences;
+	type MaxUsageBatchSize = MaxUsageBatchSize;
+	type UsageChallengePeriod = UsageChallengePeriod;
+	type UsageReportBond = UsageReportBond;
+	type UsageDisputeBond = UsageDisputeBond;
+	type UsageDisputePeriod = UsageDisputePeriod;
+	type MaxUsageAppliedPerBlock = MaxUsageAppliedPerBlock;
+	type OnDisputeSlash = ();
+	type MaxProjectMembers = MaxProjectMembers;
+	type MaxProjectChains = MaxProjectChains;
+	type MaxApiKeys = MaxApiKeys;
+	type MaxMetadataLength = MaxMetadataLength;
+	type MaxAllowedOrigins = MaxAllowedOrigins;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type MaxQuotaThresholds = MaxQuotaThresholds;
+	type UsageHistoryDepth = UsageHistoryDepth;
 	type WeightInfo = weights::SubstrateWeight<TestRuntime>;
 }
 
@@ -4,11 +4,193 @@ use super::*;
 use frame_support::pallet_prelude::*;
 
 #[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
-pub struct Project<AccountId, ChainId> {
+pub struct Project<AccountId, ChainId, Balance> {
 	pub consumer: AccountId,
+	/// Quota bucket of each chain used by the project.
+	pub chains: Vec<ChainQuota<ChainId, Balance>>,
+	pub state: ProjectState,
+}
+
+impl<AccountId, ChainId: PartialEq, Balance> Project<AccountId, ChainId, Balance> {
+	/// Quota bucket of the given chain.
+	pub fn chain(&self, chain_id: &ChainId) -> Option<&ChainQuota<ChainId, Balance>> {
+		self.chains.iter().find(|chain| &chain.chain_id == chain_id)
+	}
+
+	/// Mutable quota bucket of the given chain.
+	pub fn chain_mut(&mut self, chain_id: &ChainId) -> Option<&mut ChainQuota<ChainId, Balance>> {
+		self.chains.iter_mut().find(|chain| &chain.chain_id == chain_id)
+	}
+
+	/// `true` if the quota of every chain is used up.
+	pub fn is_exhausted(&self) -> bool {
+		self.chains.iter().all(|chain| chain.usage >= chain.quota)
+	}
+}
+
+/// Quota and usage of a project on a single chain.
+#[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ChainQuota<ChainId, Balance> {
 	pub chain_id: ChainId,
 	pub quota: u128,
 	pub usage: u128,
+	/// Price per request in effect at the latest purchase.
+	pub quota_price: Balance,
+	/// Deposit held in escrow for the quota that is not used yet.
+	pub escrow: Balance,
+	/// Deposit paid out for the quota that is not used yet, refunded if the project is closed.
+	pub paid: Balance,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ProjectState {
+	/// Project is served by gateways.
+	Active,
+	/// Project is paused by one of its admins or a regulator and is not served.
+	Suspended {
+		/// Only a regulator can resume the project if it was suspended by one.
+		by_regulator: bool,
+	},
+	/// Project used up the quota of all its chains and is not served until it is deposited.
+	Exhausted,
+	/// Project is closed and its unused quota is refunded.
+	Closed,
+}
+
+/// Metadata attached to a project, e.g. to let gateways check the origin of requests.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadata<Text, Origins> {
+	pub name: Text,
+	pub description: Text,
+	/// Origins or referrers allowed to use the project, any if empty.
+	pub allowed_origins: Origins,
+}
+
+/// Routing information published by the operator of a provider.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProviderMetadata<Text> {
+	pub region: Text,
+	pub endpoint: Text,
+	/// Client software and version, e.g. `geth/v1.10.17`.
+	pub client: Text,
+	pub capabilities: ProviderCapabilities,
+}
+
+/// Optional features served by a provider.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProviderCapabilities {
+	/// Serves historical state.
+	pub archive: bool,
+	/// Serves trace and debug methods.
+	pub trace: bool,
+}
+
+impl ProviderCapabilities {
+	/// `true` if every capability of `other` is supported too.
+	pub fn contains(&self, other: &Self) -> bool {
+		(self.archive || !other.archive) && (self.trace || !other.trace)
+	}
+}
+
+/// Hash of an API key used by gateways to authenticate requests to a project.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ApiKey<Hash, BlockNumber> {
+	pub key_hash: Hash,
+	/// Block from which the key is no longer valid, if any.
+	pub expires_at: Option<BlockNumber>,
+}
+
+/// Request rate limits of a project enforced by gateways, `None` means unlimited.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RateLimit {
+	pub requests_per_second: Option<u32>,
+	pub requests_per_day: Option<u64>,
+}
+
+impl RateLimit {
+	/// Combine two rate limits, keeping the stricter of each limit.
+	pub fn stricter(self, other: Self) -> Self {
+		fn min<N: Ord>(a: Option<N>, b: Option<N>) -> Option<N> {
+			match (a, b) {
+				(Some(a), Some(b)) => Some(a.min(b)),
+				(a, None) => a,
+				(None, b) => b,
+			}
+		}
+
+		RateLimit {
+			requests_per_second: min(self.requests_per_second, other.requests_per_second),
+			requests_per_day: min(self.requests_per_day, other.requests_per_day),
+		}
+	}
+}
+
+/// Role of a project member, each role includes the permissions of the roles below it.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ProjectRole {
+	/// Can read the project off-chain.
+	Viewer,
+	/// Can deposit to the project.
+	Depositor,
+	/// Can manage members and configure the project.
+	Admin,
+}
+
+/// Deposit automatically made to a project when one of its quota thresholds is reached.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AutoTopUp<Balance> {
+	/// Amount deposited on each top-up.
+	pub amount: Balance,
+	/// Maximum total amount that can be spent on top-ups.
+	pub max_spend: Balance,
+	/// Total amount spent on top-ups so far.
+	pub spent: Balance,
+}
+
+/// Reporting window of a usage report, inclusive on both ends.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UsagePeriod<BlockNumber> {
+	pub start: BlockNumber,
+	pub end: BlockNumber,
+}
+
+/// Usage reported by a regulator, backed by a bond reserved until the usage is settled.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UsageReport<AccountId, Balance> {
+	pub regulator: AccountId,
+	pub usage: u128,
+	pub bond: Balance,
+}
+
+/// Usage accepted by the quorum, applied to the project once the challenge period is over.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingUsage<AccountId, Balance, BlockNumber> {
+	pub usage: u128,
+	/// Regulators whose reports were accepted, with their reserved bonds.
+	pub reporters: Vec<(AccountId, Balance)>,
+	/// Block at which the usage is applied unless it is disputed.
+	pub challenge_end: BlockNumber,
+	pub dispute: Option<UsageDispute<AccountId, Balance, BlockNumber>>,
+}
+
+/// Dispute opened by the consumer of a project against pending usage.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UsageDispute<AccountId, Balance, BlockNumber> {
+	pub consumer: AccountId,
+	pub bond: Balance,
+	/// Regulator votes, `true` if in favour of the consumer.
+	pub votes: Vec<(AccountId, bool)>,
+	/// Block at which the usage is applied if the dispute is not resolved.
+	pub deadline: BlockNumber,
+}
+
+/// Quorum of regulators required to accept a usage report.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UsageQuorum {
+	/// Number of matching reports needed.
+	pub threshold: u32,
+	/// Maximum relative difference between reports that are considered matching.
+	pub tolerance: Perbill,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -19,18 +201,35 @@ pub enum ProviderType {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
-pub enum ProviderState {
+pub enum ProviderState<BlockNumber> {
 	Registered,
 	Active,
 	InActive,
+	/// Provider taken out of service for an offence until the given block.
+	Jailed {
+		until_block: BlockNumber,
+	},
+	/// Self-registered provider waiting for a regulator to approve it.
+	PendingApproval,
+	/// Self-registered provider turned down by a regulator.
+	Rejected,
 }
 
 #[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
-pub struct Provider<AccountId, ChainId> {
+pub struct Provider<AccountId, ChainId, BlockNumber> {
 	pub provider_type: ProviderType,
 	pub operator: AccountId,
 	pub chain_id: ChainId,
-	pub state: ProviderState,
+	pub state: ProviderState<BlockNumber>,
+}
+
+/// Offences reported for a provider within the current offence window.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProviderOffences<BlockNumber> {
+	/// Number of offences reported in the window.
+	pub count: u32,
+	/// Block at which the window started.
+	pub since: BlockNumber,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
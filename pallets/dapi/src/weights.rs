@@ -3,6 +3,10 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-04-12, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! PLACEHOLDER WEIGHTS: most weights below were written or adjusted by hand after that run,
+//! estimated from the storage accesses of each call rather than measured. Regenerate this file
+//! with the command below before relying on them.
 
 // Executed Command:
 // ./target/release/massbit-collator
@@ -53,6 +57,84 @@ pub trait WeightInfo {
 	fn add_regulator() -> Weight;
 	#[rustfmt::skip]
 	fn remove_regulator() -> Weight;
+	#[rustfmt::skip]
+	fn set_quota_price() -> Weight;
+	#[rustfmt::skip]
+	fn set_chain_quota_price() -> Weight;
+	#[rustfmt::skip]
+	fn close_project() -> Weight;
+	#[rustfmt::skip]
+	fn submit_project_usage() -> Weight;
+	#[rustfmt::skip]
+	fn submit_project_usage_batch(n: u32, ) -> Weight;
+	#[rustfmt::skip]
+	fn apply_project_usage() -> Weight;
+	#[rustfmt::skip]
+	fn set_usage_quorum() -> Weight;
+	#[rustfmt::skip]
+	fn dispute_project_usage() -> Weight;
+	#[rustfmt::skip]
+	fn resolve_usage_dispute() -> Weight;
+	#[rustfmt::skip]
+	fn set_quota_thresholds() -> Weight;
+	#[rustfmt::skip]
+	fn set_auto_top_up() -> Weight;
+	#[rustfmt::skip]
+	fn suspend_project() -> Weight;
+	#[rustfmt::skip]
+	fn resume_project() -> Weight;
+	#[rustfmt::skip]
+	fn delete_project(h: u32, ) -> Weight;
+	#[rustfmt::skip]
+	fn transfer_project() -> Weight;
+	#[rustfmt::skip]
+	fn cancel_project_transfer() -> Weight;
+	#[rustfmt::skip]
+	fn accept_project_transfer() -> Weight;
+	#[rustfmt::skip]
+	fn add_project_member() -> Weight;
+	#[rustfmt::skip]
+	fn remove_project_member() -> Weight;
+	#[rustfmt::skip]
+	fn set_project_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn clear_project_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn add_api_key() -> Weight;
+	#[rustfmt::skip]
+	fn revoke_api_key() -> Weight;
+	#[rustfmt::skip]
+	fn rotate_api_key() -> Weight;
+	#[rustfmt::skip]
+	fn set_project_rate_limit() -> Weight;
+	#[rustfmt::skip]
+	fn set_project_rate_limit_cap() -> Weight;
+	#[rustfmt::skip]
+	fn add_project_chain() -> Weight;
+	#[rustfmt::skip]
+	fn create_project() -> Weight;
+	#[rustfmt::skip]
+	fn create_provider() -> Weight;
+	#[rustfmt::skip]
+	fn self_register_provider() -> Weight;
+	#[rustfmt::skip]
+	fn approve_provider() -> Weight;
+	#[rustfmt::skip]
+	fn reject_provider() -> Weight;
+	#[rustfmt::skip]
+	fn set_provider_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn clear_provider_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn report_provider_offence() -> Weight;
+	#[rustfmt::skip]
+	fn reactivate_provider() -> Weight;
+	#[rustfmt::skip]
+	fn transfer_provider() -> Weight;
+	#[rustfmt::skip]
+	fn cancel_provider_transfer() -> Weight;
+	#[rustfmt::skip]
+	fn accept_provider_transfer() -> Weight;
 }
 
 /// Weights for pallet_dapi using the Substrate node and recommended hardware.
@@ -60,21 +142,26 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Dapi Projects (r:1 w:1)
 	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:1)
 	#[rustfmt::skip]
 	fn register_project() -> Weight {
-		(35_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
 	#[rustfmt::skip]
 	fn deposit_project() -> Weight {
-		(28_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Dapi Providers (r:1 w:1)
@@ -94,13 +181,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
 	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
 	#[rustfmt::skip]
 	fn unregister_provider() -> Weight {
 		(15_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Dapi ChainIds (r:1 w:1)
 	#[rustfmt::skip]
@@ -110,11 +198,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Dapi ChainIds (r:1 w:1)
+	// Storage: Dapi ChainQuotaPrices (r:0 w:1)
 	#[rustfmt::skip]
 	fn remove_chain_id() -> Weight {
-		(9_000_000 as Weight)
+		(10_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	// Storage: Dapi Regulators (r:1 w:1)
 	#[rustfmt::skip]
@@ -124,33 +213,465 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Dapi Regulators (r:1 w:1)
+	// Storage: Dapi UsageQuorumConfig (r:1 w:0)
 	#[rustfmt::skip]
 	fn remove_regulator() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi QuotaPrice (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_quota_price() -> Weight {
+		(7_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_chain_quota_price() -> Weight {
 		(10_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi PendingUsages (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Dapi LastUsagePeriod (r:0 w:1)
+	// Storage: Dapi QuotaThresholds (r:0 w:1)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	// Storage: Dapi PendingUsageReports (r:1 w:1)
+	#[rustfmt::skip]
+	fn close_project() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi LastUsagePeriod (r:1 w:1)
+	// Storage: Dapi PendingUsageReports (r:2 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Dapi UsageQuorumConfig (r:1 w:0)
+	// Storage: Dapi PendingUsages (r:0 w:1)
+	// Storage: Dapi UsageChallengeEnds (r:1 w:1)
+	#[rustfmt::skip]
+	fn submit_project_usage() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi UsageQuorumConfig (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi LastUsagePeriod (r:1 w:1)
+	// Storage: Dapi PendingUsageReports (r:2 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Dapi PendingUsages (r:0 w:1)
+	// Storage: Dapi UsageChallengeEnds (r:1 w:1)
+	#[rustfmt::skip]
+	fn submit_project_usage_batch(n: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((36_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi QuotaThresholds (r:1 w:0)
+	// Storage: Dapi AutoTopUps (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: Dapi ProjectUsageByEra (r:2 w:2)
+	// Storage: Dapi ProjectUsageEras (r:1 w:1)
+	#[rustfmt::skip]
+	fn apply_project_usage() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi UsageQuorumConfig (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_usage_quorum() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi PendingUsages (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn dispute_project_usage() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Dapi PendingUsages (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi QuotaThresholds (r:1 w:0)
+	// Storage: Dapi AutoTopUps (r:1 w:1)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: Dapi ProjectUsageByEra (r:2 w:1)
+	#[rustfmt::skip]
+	fn resolve_usage_dispute() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi QuotaThresholds (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_quota_thresholds() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_auto_top_up() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	#[rustfmt::skip]
+	fn suspend_project() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	#[rustfmt::skip]
+	fn resume_project() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi PendingUsages (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Dapi LastUsagePeriod (r:0 w:1)
+	// Storage: Dapi QuotaThresholds (r:0 w:1)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	// Storage: Dapi PendingUsageReports (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:1)
+	// Storage: Dapi ProjectMembers (r:0 w:1)
+	// Storage: Dapi ProjectApiKeys (r:0 w:1)
+	// Storage: Dapi ProjectRateLimits (r:0 w:1)
+	// Storage: Dapi ProjectRateLimitCaps (r:0 w:1)
+	// Storage: Dapi ProjectMetadataStore (r:0 w:1)
+	// Storage: Dapi ProjectMetadataDeposits (r:1 w:1)
+	// Storage: Dapi ProjectUsageEras (r:1 w:1)
+	// Storage: Dapi ProjectUsageByEra (r:0 w:1)
+	#[rustfmt::skip]
+	fn delete_project(h: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi PendingProjectTransfers (r:0 w:1)
+	#[rustfmt::skip]
+	fn transfer_project() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi PendingProjectTransfers (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_project_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi PendingProjectTransfers (r:1 w:1)
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:2)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	#[rustfmt::skip]
+	fn accept_project_transfer() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:1)
+	#[rustfmt::skip]
+	fn add_project_member() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:1)
+	#[rustfmt::skip]
+	fn remove_project_member() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: Dapi ProjectMetadataStore (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_project_metadata() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMetadataStore (r:1 w:1)
+	// Storage: Dapi ProjectMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn clear_project_metadata() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectApiKeys (r:1 w:1)
+	#[rustfmt::skip]
+	fn add_api_key() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectApiKeys (r:1 w:1)
+	#[rustfmt::skip]
+	fn revoke_api_key() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectApiKeys (r:1 w:1)
+	#[rustfmt::skip]
+	fn rotate_api_key() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectRateLimits (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_project_rate_limit() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectRateLimitCaps (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_project_rate_limit_cap() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn add_project_chain() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi MassbitIdNonce (r:1 w:1)
+	// Storage: System ParentHash (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_project() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi MassbitIdNonce (r:1 w:1)
+	// Storage: System ParentHash (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ProvidersByOperator (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_provider() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ProvidersByOperator (r:0 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking Ledger (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
+	// Storage: DapiStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	#[rustfmt::skip]
+	fn self_register_provider() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	#[rustfmt::skip]
+	fn approve_provider() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	#[rustfmt::skip]
+	fn reject_provider() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi ProviderMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: Dapi ProviderMetadataStore (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_provider_metadata() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi ProviderMetadataStore (r:1 w:1)
+	// Storage: Dapi ProviderMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn clear_provider_metadata() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ProviderOffenceCounts (r:1 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	// Storage: Dapi OffenceDeactivations (r:0 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:2 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking UnappliedSlashes (r:1 w:1)
+	#[rustfmt::skip]
+	fn report_provider_offence() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking ProviderEraStake (r:2 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking Ledger (r:1 w:1)
+	// Storage: DapiStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Dapi OffenceDeactivations (r:1 w:1)
+	#[rustfmt::skip]
+	fn reactivate_provider() -> Weight {
+		(54_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi PendingProviderTransfers (r:0 w:1)
+	#[rustfmt::skip]
+	fn transfer_provider() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi PendingProviderTransfers (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_provider_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi PendingProviderTransfers (r:1 w:1)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ProvidersByOperator (r:0 w:2)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: DapiStaking GeneralStakerInfo (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DapiStaking Ledger (r:2 w:2)
+	// Storage: Balances Locks (r:2 w:2)
+	#[rustfmt::skip]
+	fn accept_provider_transfer() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: Dapi Projects (r:1 w:1)
 	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:1)
 	#[rustfmt::skip]
 	fn register_project() -> Weight {
-		(35_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
 	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
 	#[rustfmt::skip]
 	fn deposit_project() -> Weight {
-		(28_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Dapi Providers (r:1 w:1)
@@ -170,13 +691,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
 	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
 	// Storage: DapiStaking CurrentEra (r:1 w:0)
 	#[rustfmt::skip]
 	fn unregister_provider() -> Weight {
 		(15_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Dapi ChainIds (r:1 w:1)
 	#[rustfmt::skip]
@@ -186,11 +708,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Dapi ChainIds (r:1 w:1)
+	// Storage: Dapi ChainQuotaPrices (r:0 w:1)
 	#[rustfmt::skip]
 	fn remove_chain_id() -> Weight {
-		(9_000_000 as Weight)
+		(10_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 	// Storage: Dapi Regulators (r:1 w:1)
 	#[rustfmt::skip]
@@ -200,10 +723,437 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Dapi Regulators (r:1 w:1)
+	// Storage: Dapi UsageQuorumConfig (r:1 w:0)
 	#[rustfmt::skip]
 	fn remove_regulator() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi QuotaPrice (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_quota_price() -> Weight {
+		(7_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_chain_quota_price() -> Weight {
 		(10_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi PendingUsages (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Dapi LastUsagePeriod (r:0 w:1)
+	// Storage: Dapi QuotaThresholds (r:0 w:1)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	// Storage: Dapi PendingUsageReports (r:1 w:1)
+	#[rustfmt::skip]
+	fn close_project() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi LastUsagePeriod (r:1 w:1)
+	// Storage: Dapi PendingUsageReports (r:2 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Dapi UsageQuorumConfig (r:1 w:0)
+	// Storage: Dapi PendingUsages (r:0 w:1)
+	// Storage: Dapi UsageChallengeEnds (r:1 w:1)
+	#[rustfmt::skip]
+	fn submit_project_usage() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi UsageQuorumConfig (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi LastUsagePeriod (r:1 w:1)
+	// Storage: Dapi PendingUsageReports (r:2 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Dapi PendingUsages (r:0 w:1)
+	// Storage: Dapi UsageChallengeEnds (r:1 w:1)
+	#[rustfmt::skip]
+	fn submit_project_usage_batch(n: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((36_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi QuotaThresholds (r:1 w:0)
+	// Storage: Dapi AutoTopUps (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: Dapi ProjectUsageByEra (r:2 w:2)
+	// Storage: Dapi ProjectUsageEras (r:1 w:1)
+	#[rustfmt::skip]
+	fn apply_project_usage() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi UsageQuorumConfig (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_usage_quorum() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi PendingUsages (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn dispute_project_usage() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Dapi PendingUsages (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi QuotaThresholds (r:1 w:0)
+	// Storage: Dapi AutoTopUps (r:1 w:1)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: Dapi ProjectUsageByEra (r:2 w:1)
+	#[rustfmt::skip]
+	fn resolve_usage_dispute() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi QuotaThresholds (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_quota_thresholds() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_auto_top_up() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	#[rustfmt::skip]
+	fn suspend_project() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	#[rustfmt::skip]
+	fn resume_project() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi PendingUsages (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Dapi LastUsagePeriod (r:0 w:1)
+	// Storage: Dapi QuotaThresholds (r:0 w:1)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	// Storage: Dapi PendingUsageReports (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:1)
+	// Storage: Dapi ProjectMembers (r:0 w:1)
+	// Storage: Dapi ProjectApiKeys (r:0 w:1)
+	// Storage: Dapi ProjectRateLimits (r:0 w:1)
+	// Storage: Dapi ProjectRateLimitCaps (r:0 w:1)
+	// Storage: Dapi ProjectMetadataStore (r:0 w:1)
+	// Storage: Dapi ProjectMetadataDeposits (r:1 w:1)
+	// Storage: Dapi ProjectUsageEras (r:1 w:1)
+	// Storage: Dapi ProjectUsageByEra (r:0 w:1)
+	#[rustfmt::skip]
+	fn delete_project(h: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(h as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(h as Weight)))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi PendingProjectTransfers (r:0 w:1)
+	#[rustfmt::skip]
+	fn transfer_project() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi PendingProjectTransfers (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_project_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi PendingProjectTransfers (r:1 w:1)
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:2)
+	// Storage: Dapi AutoTopUps (r:0 w:1)
+	#[rustfmt::skip]
+	fn accept_project_transfer() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:1)
+	#[rustfmt::skip]
+	fn add_project_member() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:1)
+	#[rustfmt::skip]
+	fn remove_project_member() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: Dapi ProjectMetadataStore (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_project_metadata() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMetadataStore (r:1 w:1)
+	// Storage: Dapi ProjectMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn clear_project_metadata() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectApiKeys (r:1 w:1)
+	#[rustfmt::skip]
+	fn add_api_key() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectApiKeys (r:1 w:1)
+	#[rustfmt::skip]
+	fn revoke_api_key() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectApiKeys (r:1 w:1)
+	#[rustfmt::skip]
+	fn rotate_api_key() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ProjectRateLimits (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_project_rate_limit() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:0)
+	// Storage: Dapi ProjectRateLimitCaps (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_project_rate_limit_cap() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ProjectMembers (r:1 w:0)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn add_project_chain() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi MassbitIdNonce (r:1 w:1)
+	// Storage: System ParentHash (r:1 w:0)
+	// Storage: Dapi Projects (r:1 w:1)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ChainQuotaPrices (r:1 w:0)
+	// Storage: Dapi QuotaPrice (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DapiStaking BlockRewardAccumulator (r:1 w:1)
+	// Storage: Dapi ProjectsByConsumer (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_project() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi MassbitIdNonce (r:1 w:1)
+	// Storage: System ParentHash (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ProvidersByOperator (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_provider() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ChainIds (r:1 w:0)
+	// Storage: Dapi ProvidersByOperator (r:0 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking Ledger (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking ProviderEraStake (r:1 w:1)
+	// Storage: DapiStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	#[rustfmt::skip]
+	fn self_register_provider() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	#[rustfmt::skip]
+	fn approve_provider() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	#[rustfmt::skip]
+	fn reject_provider() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi ProviderMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: Dapi ProviderMetadataStore (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_provider_metadata() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi ProviderMetadataStore (r:1 w:1)
+	// Storage: Dapi ProviderMetadataDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+	fn clear_provider_metadata() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ProviderOffenceCounts (r:1 w:1)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	// Storage: Dapi OffenceDeactivations (r:0 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:2 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking UnappliedSlashes (r:1 w:1)
+	#[rustfmt::skip]
+	fn report_provider_offence() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi Regulators (r:1 w:0)
+	// Storage: Dapi ActiveProvidersByChain (r:0 w:1)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: DapiStaking CurrentEra (r:1 w:0)
+	// Storage: DapiStaking ProviderEraStake (r:2 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: DapiStaking Ledger (r:1 w:1)
+	// Storage: DapiStaking GeneralStakerInfo (r:1 w:1)
+	// Storage: DapiStaking GeneralEraInfo (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Dapi OffenceDeactivations (r:1 w:1)
+	#[rustfmt::skip]
+	fn reactivate_provider() -> Weight {
+		(54_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi PendingProviderTransfers (r:0 w:1)
+	#[rustfmt::skip]
+	fn transfer_provider() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi Providers (r:1 w:0)
+	// Storage: Dapi PendingProviderTransfers (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_provider_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dapi PendingProviderTransfers (r:1 w:1)
+	// Storage: Dapi Providers (r:1 w:1)
+	// Storage: Dapi ProvidersByOperator (r:0 w:2)
+	// Storage: DapiStaking RegisteredProviders (r:1 w:1)
+	// Storage: DapiStaking GeneralStakerInfo (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DapiStaking Ledger (r:2 w:2)
+	// Storage: Balances Locks (r:2 w:2)
+	#[rustfmt::skip]
+	fn accept_provider_transfer() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
 }
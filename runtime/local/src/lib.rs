@@ -15,7 +15,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -61,10 +64,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("massbit-local"),
 	impl_name: create_runtime_str!("massbit-local"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -286,6 +289,10 @@ parameter_types! {
 	pub const MaxUnlockingChunks: u32 = 2;
 	pub const UnbondingPeriod: u32 = 2;
 	pub const MaxEraStakeValues: u32 = 5;
+	pub const OutOfSyncSlash: Perbill = Perbill::from_percent(10);
+	pub const BadPerformanceSlash: Perbill = Perbill::from_percent(5);
+	pub const SlashStakers: bool = false;
+	pub const SlashDeferDuration: u32 = 1;
 }
 
 impl pallet_dapi_staking::Config for Runtime {
@@ -301,6 +308,11 @@ impl pallet_dapi_staking::Config for Runtime {
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type UnbondingPeriod = UnbondingPeriod;
 	type MaxEraStakeValues = MaxEraStakeValues;
+	type OutOfSyncSlash = OutOfSyncSlash;
+	type BadPerformanceSlash = BadPerformanceSlash;
+	type SlashStakers = SlashStakers;
+	type SlashDeferDuration = SlashDeferDuration;
+	type OnSlash = OnProjectPayment;
 	type Event = Event;
 	type WeightInfo = pallet_dapi_staking::weights::SubstrateWeight<Runtime>;
 }
@@ -314,6 +326,30 @@ impl OnUnbalanced<NegativeImbalance> for OnProjectPayment {
 
 parameter_types! {
 	pub const MaxBytesInChainId: u32 = 64;
+	pub const DefaultQuotaPrice: Balance = MILLIMBT;
+	pub const ProjectRefundPalletId: PalletId = PalletId(*b"py/dapir");
+	pub ProjectRefundAccount: AccountId = ProjectRefundPalletId::get().into_account();
+	pub const DapiPalletId: PalletId = PalletId(*b"py/dapi_");
+	pub const ProjectDepositEscrow: bool = true;
+	pub const RequireProviderApproval: bool = true;
+	pub const JailDuration: BlockNumber = 1 * HOURS;
+	pub const OffenceWindow: BlockNumber = 1 * DAYS;
+	pub const MaxOffences: u32 = 3;
+	pub const MaxUsageBatchSize: u32 = 1_000;
+	pub const UsageChallengePeriod: BlockNumber = 10 * MINUTES;
+	pub const UsageReportBond: Balance = 100 * MILLIMBT;
+	pub const UsageDisputeBond: Balance = 10 * MBT;
+	pub const UsageDisputePeriod: BlockNumber = 1 * DAYS;
+	pub const MaxUsageAppliedPerBlock: u32 = 100;
+	pub const UsageHistoryDepth: u32 = 1_512;
+	pub const MaxQuotaThresholds: u32 = 8;
+	pub const MaxProjectMembers: u32 = 32;
+	pub const MaxProjectChains: u32 = 8;
+	pub const MaxApiKeys: u32 = 16;
+	pub const MaxMetadataLength: u32 = 256;
+	pub const MaxAllowedOrigins: u32 = 16;
+	pub const MetadataDepositBase: Balance = 10 * MILLIMBT;
+	pub const MetadataDepositPerByte: Balance = MILLIMBT / 10;
 }
 
 impl pallet_dapi::Config for Runtime {
@@ -321,9 +357,34 @@ impl pallet_dapi::Config for Runtime {
 	type Currency = Balances;
 	type DapiStaking = DapiStaking;
 	type UpdateRegulatorOrigin = EnsureRoot<AccountId>;
+	type UpdateQuotaPriceOrigin = EnsureRoot<AccountId>;
+	type DefaultQuotaPrice = DefaultQuotaPrice;
 	type ChainIdMaxLength = MaxBytesInChainId;
 	type MassbitId = MassbitId;
 	type OnProjectPayment = OnProjectPayment;
+	type ProjectRefundAccount = ProjectRefundAccount;
+	type PalletId = DapiPalletId;
+	type ProjectDepositEscrow = ProjectDepositEscrow;
+	type RequireProviderApproval = RequireProviderApproval;
+	type JailDuration = JailDuration;
+	type OffenceWindow = OffenceWindow;
+	type MaxOffences = MaxOffences;
+	type MaxUsageBatchSize = MaxUsageBatchSize;
+	type UsageChallengePeriod = UsageChallengePeriod;
+	type UsageReportBond = UsageReportBond;
+	type UsageDisputeBond = UsageDisputeBond;
+	type UsageDisputePeriod = UsageDisputePeriod;
+	type MaxUsageAppliedPerBlock = MaxUsageAppliedPerBlock;
+	type OnDisputeSlash = OnProjectPayment;
+	type MaxProjectMembers = MaxProjectMembers;
+	type MaxProjectChains = MaxProjectChains;
+	type MaxApiKeys = MaxApiKeys;
+	type MaxMetadataLength = MaxMetadataLength;
+	type MaxAllowedOrigins = MaxAllowedOrigins;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type MaxQuotaThresholds = MaxQuotaThresholds;
+	type UsageHistoryDepth = UsageHistoryDepth;
 	type WeightInfo = pallet_dapi::weights::SubstrateWeight<Runtime>;
 }
 
@@ -516,6 +577,49 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_dapi_runtime_api::DapiApi<Block, AccountId, MassbitId, Hash> for Runtime {
+		fn project_metadata(
+			project_id: MassbitId,
+		) -> Option<pallet_dapi::ProjectMetadata<Vec<u8>, Vec<Vec<u8>>>> {
+			Dapi::project_metadata(&project_id)
+		}
+
+		fn is_valid_api_key(project_id: MassbitId, key_hash: Hash) -> bool {
+			Dapi::is_valid_api_key(&project_id, &key_hash)
+		}
+
+		fn project_rate_limit(project_id: MassbitId) -> Option<pallet_dapi::RateLimit> {
+			Dapi::project_rate_limit(&project_id)
+		}
+
+		fn projects_by_consumer(consumer: AccountId, start: u32, limit: u32) -> Vec<MassbitId> {
+			Dapi::projects_of(&consumer, start, limit)
+		}
+
+		fn providers_by_operator(operator: AccountId, start: u32, limit: u32) -> Vec<MassbitId> {
+			Dapi::providers_of(&operator, start, limit)
+		}
+
+		fn active_providers_by_chain(chain_id: Vec<u8>, start: u32, limit: u32) -> Vec<MassbitId> {
+			Dapi::active_providers_of(chain_id, start, limit)
+		}
+
+		fn provider_metadata(
+			provider_id: MassbitId,
+		) -> Option<pallet_dapi::ProviderMetadata<Vec<u8>>> {
+			Dapi::provider_metadata(&provider_id)
+		}
+
+		fn active_providers_with_capabilities(
+			chain_id: Vec<u8>,
+			capabilities: pallet_dapi::ProviderCapabilities,
+			start: u32,
+			limit: u32,
+		) -> Vec<MassbitId> {
+			Dapi::active_providers_with(chain_id, capabilities, start, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,